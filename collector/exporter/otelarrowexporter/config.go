@@ -55,6 +55,12 @@ type ArrowSettings struct {
 	// grpc: keepalive: max_connection_age_grace plus the timeout.
 	MaxStreamLifetime time.Duration `mapstructure:"max_stream_lifetime"`
 
+	// MaxStartupJitter delays the start of each initial stream by
+	// a random duration in [0, MaxStartupJitter).  This spreads
+	// the reconnect load when many exporters restart together.
+	// Zero (the default) disables the delay.
+	MaxStartupJitter time.Duration `mapstructure:"max_startup_jitter"`
+
 	// Zstd settings apply to OTel-Arrow use of gRPC specifically.
 	// Note that when multiple Otel-Arrow exporters are configured
 	// their settings will be applied in arbitrary order.
@@ -95,6 +101,10 @@ func (cfg *ArrowSettings) Validate() error {
 		return fmt.Errorf("max stream life must be > 0: %d", cfg.MaxStreamLifetime)
 	}
 
+	if cfg.MaxStartupJitter < 0 {
+		return fmt.Errorf("max startup jitter must be >= 0: %d", cfg.MaxStartupJitter)
+	}
+
 	if err := cfg.Zstd.Validate(); err != nil {
 		return fmt.Errorf("zstd encoder: invalid configuration: %w", err)
 	}
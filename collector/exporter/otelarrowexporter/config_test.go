@@ -85,6 +85,7 @@ func TestUnmarshalConfig(t *testing.T) {
 				NumStreams:         2,
 				EnableMixedSignals: true,
 				MaxStreamLifetime:  2 * time.Hour,
+				MaxStartupJitter:   5 * time.Second,
 				PayloadCompression: configcompression.Zstd,
 				Zstd:               zstd.DefaultEncoderConfig(),
 			},
@@ -117,6 +118,12 @@ func TestArrowSettingsValidate(t *testing.T) {
 	require.Error(t, settings(true, math.MinInt, 10*time.Second, zstd.DefaultLevel).Validate())
 	require.Error(t, settings(true, math.MaxInt, 10*time.Second, zstd.MinLevel-1).Validate())
 	require.Error(t, settings(true, math.MaxInt, 10*time.Second, zstd.MaxLevel+1).Validate())
+
+	jittered := settings(true, 1, 10*time.Second, zstd.DefaultLevel)
+	jittered.MaxStartupJitter = 5 * time.Second
+	require.NoError(t, jittered.Validate())
+	jittered.MaxStartupJitter = -1 * time.Second
+	require.Contains(t, jittered.Validate().Error(), "max startup jitter must be")
 }
 
 func TestDefaultSettingsValid(t *testing.T) {
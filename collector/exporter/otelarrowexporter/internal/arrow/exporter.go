@@ -28,6 +28,14 @@ type Exporter struct {
 
 	maxStreamLifetime time.Duration
 
+	// maxStartupJitter bounds the random delay applied before
+	// each of the initial streams is started.
+	maxStartupJitter time.Duration
+
+	// int63n is the random source of the startup delay, it is
+	// rand.Int63n except in tests.
+	int63n func(int64) int64
+
 	// disableDowngrade prevents downgrade from occurring, supports
 	// forcing Arrow transport.
 	disableDowngrade bool
@@ -103,6 +111,7 @@ func MakeAnyStreamClient[T AnyStreamClient](method string, clientFunc func(ctx c
 // NewExporter configures a new Exporter.
 func NewExporter(
 	maxStreamLifetime time.Duration,
+	maxStartupJitter time.Duration,
 	numStreams int,
 	disableDowngrade bool,
 	telemetry component.TelemetrySettings,
@@ -114,6 +123,8 @@ func NewExporter(
 ) *Exporter {
 	return &Exporter{
 		maxStreamLifetime: maxStreamLifetime,
+		maxStartupJitter:  maxStartupJitter,
+		int63n:            rand.Int63n,
 		numStreams:        numStreams,
 		disableDowngrade:  disableDowngrade,
 		telemetry:         telemetry,
@@ -153,7 +164,7 @@ func (e *Exporter) runStreamController(bgctx context.Context) {
 	// Start the initial number of streams
 	for i := 0; i < running; i++ {
 		e.wg.Add(1)
-		go e.runArrowStream(bgctx)
+		go e.runInitialArrowStream(bgctx)
 	}
 
 	for {
@@ -194,6 +205,32 @@ func addJitter(v time.Duration) time.Duration {
 	return v - time.Duration(rand.Int63n(int64(v/20)))
 }
 
+// startupDelay returns a random duration in [0, maxStartupJitter).
+func (e *Exporter) startupDelay() time.Duration {
+	if e.maxStartupJitter <= 0 {
+		return 0
+	}
+	return time.Duration(e.int63n(int64(e.maxStartupJitter)))
+}
+
+// runInitialArrowStream waits for the startup delay, if any, then
+// calls runArrowStream.  Streams restarted by the stream controller
+// do not wait, their lifetimes are already spread by addJitter.
+func (e *Exporter) runInitialArrowStream(ctx context.Context) {
+	if delay := e.startupDelay(); delay > 0 {
+		timer := time.NewTimer(delay)
+		select {
+		case <-timer.C:
+		case <-ctx.Done():
+			// Shutting down before the stream was started.
+			timer.Stop()
+			e.wg.Done()
+			return
+		}
+	}
+	e.runArrowStream(ctx)
+}
+
 // runArrowStream begins one gRPC stream using a child of the background context.
 // If the stream connection is successful, this goroutine starts another goroutine
 // to call writeStream() and performs readStream() itself.  When the stream shuts
@@ -8,6 +8,8 @@ import (
 	"encoding/json"
 	"errors"
 	"fmt"
+	"math/rand"
+	"sort"
 	"sync"
 	"sync/atomic"
 	"testing"
@@ -126,7 +128,7 @@ func newExporterTestCaseCommon(t *testing.T, noisy noisyTest, numStreams int, di
 		})
 	}
 
-	exp := NewExporter(defaultMaxStreamLifetime, numStreams, disableDowngrade, ctc.telset, nil, func() arrowRecord.ProducerAPI {
+	exp := NewExporter(defaultMaxStreamLifetime, 0, numStreams, disableDowngrade, ctc.telset, nil, func() arrowRecord.ProducerAPI {
 		// Mock the close function, use a real producer for testing dataflow.
 		mock := arrowRecordMock.NewMockProducerAPI(ctc.ctrl)
 		prod := arrowRecord.NewProducer()
@@ -585,3 +587,96 @@ func TestAddJitter(t *testing.T) {
 		require.Less(t, x, 20*time.Minute)
 	}
 }
+
+func TestStartupDelay(t *testing.T) {
+	exp := &Exporter{int63n: rand.Int63n}
+	require.Equal(t, time.Duration(0), exp.startupDelay())
+
+	// Expect a delay in [0, maxStartupJitter) in each trial.
+	exp.maxStartupJitter = time.Minute
+	for i := 0; i < 100; i++ {
+		x := exp.startupDelay()
+		require.LessOrEqual(t, time.Duration(0), x)
+		require.Less(t, x, time.Minute)
+	}
+}
+
+// TestArrowExporterStartupJitter tests that each initial stream
+// connects after its startup delay, and soon after that.
+func TestArrowExporterStartupJitter(t *testing.T) {
+	const numStreams = 4
+	const jitter = 400 * time.Millisecond
+
+	tc := newExporterTestCaseCommon(t, NotNoisy, numStreams, false, nil)
+	tc.exporter.maxStartupJitter = jitter
+
+	// Force known delays of 80ms, 160ms, 240ms and 320ms.
+	var mu sync.Mutex
+	var forced []time.Duration
+	tc.exporter.int63n = func(n int64) int64 {
+		assert.Equal(t, int64(jitter), n)
+		mu.Lock()
+		defer mu.Unlock()
+		d := time.Duration(len(forced)+1) * jitter / 5
+		forced = append(forced, d)
+		return int64(d)
+	}
+
+	newStream := tc.repeatedNewStream(func() testChannel {
+		return newHealthyTestChannel()
+	})
+
+	var delays []time.Duration
+	start := time.Now()
+
+	tc.streamCall.Times(numStreams).DoAndReturn(func(ctx context.Context, opts ...grpc.CallOption) (
+		arrowpb.ArrowStreamService_ArrowStreamClient,
+		error,
+	) {
+		mu.Lock()
+		delays = append(delays, time.Since(start))
+		mu.Unlock()
+		return newStream(ctx, opts...)
+	})
+
+	bg := context.Background()
+	require.NoError(t, tc.exporter.Start(bg))
+
+	require.Eventually(t, func() bool {
+		mu.Lock()
+		defer mu.Unlock()
+		return len(delays) == numStreams
+	}, 10*jitter, jitter/20)
+
+	require.NoError(t, tc.exporter.Shutdown(bg))
+
+	// Streams may draw their delay in any order, so compare the
+	// sorted delays: the k-th stream to connect must wait at least
+	// the k-th smallest forced delay.
+	mu.Lock()
+	defer mu.Unlock()
+	require.Len(t, forced, numStreams)
+	sort.Slice(forced, func(i, j int) bool { return forced[i] < forced[j] })
+	sort.Slice(delays, func(i, j int) bool { return delays[i] < delays[j] })
+	for i := range delays {
+		require.GreaterOrEqual(t, delays[i], forced[i])
+		// Allow for scheduling delay.
+		require.Less(t, delays[i], forced[i]+jitter/2)
+	}
+}
+
+// TestArrowExporterStartupJitterShutdown tests that shutdown does
+// not wait for streams that are still waiting to start.
+func TestArrowExporterStartupJitterShutdown(t *testing.T) {
+	tc := newSingleStreamTestCase(t)
+	tc.exporter.maxStartupJitter = time.Hour
+	// Force the longest delay so the stream can't start before shutdown.
+	tc.exporter.int63n = func(n int64) int64 {
+		return n - 1
+	}
+
+	// The stream is never started: tc.streamCall expects no calls.
+	bg := context.Background()
+	require.NoError(t, tc.exporter.Start(bg))
+	require.NoError(t, tc.exporter.Shutdown(bg))
+}
@@ -147,7 +147,7 @@ func (e *baseExporter) start(ctx context.Context, host component.Host) (err erro
 			arrowCallOpts = append(arrowCallOpts, e.config.Arrow.Zstd.CallOption())
 		}
 
-		e.arrow = arrow.NewExporter(e.config.Arrow.MaxStreamLifetime, e.config.Arrow.NumStreams, e.config.Arrow.DisableDowngrade, e.settings.TelemetrySettings, arrowCallOpts, func() arrowRecord.ProducerAPI {
+		e.arrow = arrow.NewExporter(e.config.Arrow.MaxStreamLifetime, e.config.Arrow.MaxStartupJitter, e.config.Arrow.NumStreams, e.config.Arrow.DisableDowngrade, e.settings.TelemetrySettings, arrowCallOpts, func() arrowRecord.ProducerAPI {
 			return arrowRecord.NewProducerWithOptions(arrowOpts...)
 		}, e.streamClientFactory(e.config, e.clientConn), perRPCCreds, e.netReporter)
 
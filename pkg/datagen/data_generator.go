@@ -15,9 +15,11 @@
 package datagen
 
 import (
+	"math"
 	"math/rand"
 	"time"
 
+	"github.com/brianvoe/gofakeit/v6"
 	"go.opentelemetry.io/collector/pdata/pcommon"
 )
 
@@ -32,6 +34,27 @@ type Config struct {
 	ProbHistogramHasMin float64
 	// Probability of generating a metric histogram with a max
 	ProbHistogramHasMax float64
+	// Generate random attributes covering every AnyValue type (bool, empty,
+	// bytes, map and slice in addition to string, int and double). It applies
+	// to the random attributes of log records and spans, and adds random
+	// attributes to metric data points and exemplars. Resource and scope
+	// attributes built from TestEntropy ignore it.
+	AllAttributeTypes bool
+	// Range [MinRandomAttrs, MaxRandomAttrs) of the number of random
+	// attributes generated per record. When both are 0, the default range
+	// [0,10) is used. Negative values are clamped to 0, and when
+	// MaxRandomAttrs <= MinRandomAttrs every record gets exactly
+	// MinRandomAttrs attributes.
+	MinRandomAttrs int
+	MaxRandomAttrs int
+	// Mean number of random attributes generated per record. When set (> 0),
+	// the number of attributes follows a geometric distribution starting at
+	// MinRandomAttrs, i.e. most records have few attributes and a few records
+	// have many, as in real telemetry. Counts beyond the range are clamped to
+	// MaxRandomAttrs-1, which lowers the mean when it is close to the
+	// range's upper bound. When 0, the number of attributes is uniformly
+	// distributed over the range.
+	MeanRandomAttrs float64
 }
 
 func NewDefaultConfig() Config {
@@ -46,6 +69,7 @@ func NewDefaultConfig() Config {
 
 type TestEntropy struct {
 	rng   *rand.Rand
+	faker *gofakeit.Faker
 	start int64
 }
 
@@ -58,8 +82,17 @@ func NewTestEntropy(seed int64) TestEntropy {
 		Add(time.Hour * 24 * time.Duration(rng.Intn(365))).
 		UnixNano()
 
+	// The faker seed is derived from rng so that the generated data only
+	// depends on the entropy seed. gofakeit seeds itself from crypto/rand
+	// when given a zero seed, hence the remapping.
+	fakerSeed := rng.Int63()
+	if fakerSeed == 0 {
+		fakerSeed = 1
+	}
+
 	return TestEntropy{
 		rng:   rng,
+		faker: gofakeit.New(fakerSeed),
 		start: start,
 	}
 }
@@ -171,6 +204,73 @@ func (dg *DataGenerator) HasHistogramMax() bool {
 	return dg.rng.Float64() < dg.config.ProbHistogramHasMax
 }
 
+// RandomAttributes returns a random set of attributes. The number of attributes
+// follows the MinRandomAttrs/MaxRandomAttrs config range, and when the config
+// enables AllAttributeTypes, the attribute values cover every AnyValue type.
+func (dg *DataGenerator) RandomAttributes() pcommon.Map {
+	valueTypes := scalarAttributeTypes
+	if dg.config.AllAttributeTypes {
+		valueTypes = allAttributeTypes
+	}
+	return dg.TestEntropy.randomAttributes(dg.randomAttributeCount(), valueTypes)
+}
+
+// addRandomAttributes adds random attributes (see RandomAttributes) to the
+// attributes of a metric data point or exemplar when the config enables
+// AllAttributeTypes.
+func (dg *DataGenerator) addRandomAttributes(attrs pcommon.Map) {
+	if !dg.config.AllAttributeTypes {
+		return
+	}
+	dg.RandomAttributes().Range(func(k string, v pcommon.Value) bool {
+		v.CopyTo(attrs.PutEmpty(k))
+		return true
+	})
+}
+
+// randomAttributeCount returns a number of attributes drawn from the configured
+// range, see Config.MinRandomAttrs for the handling of inconsistent ranges and
+// Config.MeanRandomAttrs for the distribution.
+func (dg *DataGenerator) randomAttributeCount() int {
+	minAttrs, maxAttrs := dg.config.MinRandomAttrs, dg.config.MaxRandomAttrs
+	if minAttrs < 0 {
+		minAttrs = 0
+	}
+	if maxAttrs < 0 {
+		maxAttrs = 0
+	}
+
+	if minAttrs == 0 && maxAttrs == 0 {
+		maxAttrs = defaultMaxRandomAttrs
+	}
+	if maxAttrs <= minAttrs {
+		return minAttrs
+	}
+	if dg.config.MeanRandomAttrs > 0 {
+		return dg.skewedAttributeCount(minAttrs, maxAttrs, dg.config.MeanRandomAttrs)
+	}
+	return minAttrs + dg.rng.Intn(maxAttrs-minAttrs)
+}
+
+// skewedAttributeCount returns minAttrs plus a geometrically distributed number
+// of extra attributes with a mean of meanAttrs-minAttrs, clamped to
+// [minAttrs, maxAttrs).
+func (dg *DataGenerator) skewedAttributeCount(minAttrs, maxAttrs int, meanAttrs float64) int {
+	extraMean := meanAttrs - float64(minAttrs)
+	if extraMean <= 0 {
+		return minAttrs
+	}
+
+	// Number of failures before the first success of Bernoulli trials with a
+	// success probability p, whose mean is (1-p)/p = extraMean.
+	p := 1 / (1 + extraMean)
+	extra := math.Floor(math.Log(1-dg.rng.Float64()) / math.Log1p(-p))
+	if extra >= float64(maxAttrs-minAttrs) {
+		return maxAttrs - 1
+	}
+	return minAttrs + int(extra)
+}
+
 func (dg *DataGenerator) GenBool() bool {
 	return dg.rng.Intn(2) == 0
 }
@@ -17,7 +17,6 @@ package datagen
 import (
 	"fmt"
 
-	"github.com/brianvoe/gofakeit/v6"
 	"go.opentelemetry.io/collector/pdata/pcommon"
 )
 
@@ -45,17 +44,29 @@ func (te TestEntropy) shuffleAttrs(fs ...func(Attrs)) pcommon.Map {
 	return attrs
 }
 
+// Number of value types drawn by randomAttributes, either string, int and
+// double only, or every AnyValue type.
+const (
+	scalarAttributeTypes = 3
+	allAttributeTypes    = 8
+)
+
+// Default (exclusive) maximum number of random attributes.
+const defaultMaxRandomAttrs = 10
+
 // RandomAttributes returns a random set of attributes. The number of attributes
 // is random [0,10). The type and value of each attribute is also random.
 func (te TestEntropy) RandomAttributes() pcommon.Map {
-	count := te.rng.Intn(10)
+	return te.randomAttributes(te.rng.Intn(defaultMaxRandomAttrs), scalarAttributeTypes)
+}
 
+func (te TestEntropy) randomAttributes(count int, valueTypes int) pcommon.Map {
 	attrs := pcommon.NewMap()
 
 	for i := 0; i < count; i++ {
-		switch te.rng.Intn(3) {
+		switch te.rng.Intn(valueTypes) {
 		case 0:
-			attrs.PutStr(fmt.Sprintf("attr_%d", i), gofakeit.LoremIpsumWord())
+			attrs.PutStr(fmt.Sprintf("attr_%d", i), te.faker.LoremIpsumWord())
 		case 1:
 			attrs.PutInt(fmt.Sprintf("attr_%d", i), te.rng.Int63())
 		case 2:
@@ -65,11 +76,11 @@ func (te TestEntropy) RandomAttributes() pcommon.Map {
 		case 4:
 			attrs.PutEmpty(fmt.Sprintf("attr_%d", i))
 		case 5:
-			attrs.PutEmptyBytes(fmt.Sprintf("attr_%d", i)).FromRaw([]byte(gofakeit.LoremIpsumWord()))
+			attrs.PutEmptyBytes(fmt.Sprintf("attr_%d", i)).FromRaw([]byte(te.faker.LoremIpsumWord()))
 		case 6:
 			vMap := attrs.PutEmptyMap(fmt.Sprintf("attr_%d", i))
 			vMap.PutInt("int", te.rng.Int63())
-			vMap.PutStr("str", gofakeit.LoremIpsumWord())
+			vMap.PutStr("str", te.faker.LoremIpsumWord())
 		case 7:
 			vSlice := attrs.PutEmptySlice(fmt.Sprintf("attr_%d", i))
 			vSlice.AppendEmpty().SetBool(te.rng.Intn(2) == 0)
@@ -17,8 +17,6 @@ package datagen
 import (
 	"time"
 
-	"github.com/brianvoe/gofakeit/v6"
-
 	"go.opentelemetry.io/collector/pdata/pcommon"
 	"go.opentelemetry.io/collector/pdata/plog"
 )
@@ -90,7 +88,7 @@ func (dg *DataGenerator) logRecord(log plog.LogRecord, sev plog.SeverityNumber,
 	log.SetObservedTimestamp(dg.CurrentTime())
 	log.SetSeverityNumber(sev)
 	log.SetSeverityText(txt)
-	log.Body().SetStr(gofakeit.LoremIpsumSentence(10))
+	log.Body().SetStr(dg.faker.LoremIpsumSentence(10))
 	dg.NewStandardAttributes().CopyTo(log.Attributes())
 	log.SetTraceID(dg.Id16Bytes())
 	log.SetSpanID(dg.Id8Bytes())
@@ -102,7 +100,7 @@ func (dg *DataGenerator) complexLogRecord(log plog.LogRecord, sev plog.SeverityN
 	log.SetSeverityNumber(sev)
 	log.SetSeverityText(txt)
 	obj := log.Body().SetEmptyMap()
-	obj.PutStr("attr1", gofakeit.LoremIpsumSentence(10))
+	obj.PutStr("attr1", dg.faker.LoremIpsumSentence(10))
 	obj.PutInt("attr2", 1)
 	obj.PutDouble("attr3", 2.0)
 	obj.PutBool("attr4", true)
@@ -121,10 +119,10 @@ func (dg *DataGenerator) RandomLogRecord(log plog.LogRecord) {
 		log.SetObservedTimestamp(dg.CurrentTime())
 	}
 	if dg.GenBool() {
-		log.SetSeverityNumber(plog.SeverityNumber(gofakeit.Number(0, 4)))
+		log.SetSeverityNumber(plog.SeverityNumber(dg.faker.Number(0, 4)))
 	}
 	if dg.GenBool() {
-		log.SetSeverityText(gofakeit.LetterN(4))
+		log.SetSeverityText(dg.faker.LetterN(4))
 	}
 	if dg.GenBool() {
 		dg.RandomBody(log.Body())
@@ -139,10 +137,10 @@ func (dg *DataGenerator) RandomLogRecord(log plog.LogRecord) {
 		log.SetSpanID(dg.Id8Bytes())
 	}
 	if dg.GenBool() {
-		log.SetDroppedAttributesCount(uint32(gofakeit.Number(0, 1000)))
+		log.SetDroppedAttributesCount(uint32(dg.faker.Number(0, 1000)))
 	}
 	if dg.GenBool() {
-		log.SetFlags(plog.LogRecordFlags(gofakeit.Number(0, 1000)))
+		log.SetFlags(plog.LogRecordFlags(dg.faker.Number(0, 1000)))
 	}
 }
 
@@ -150,7 +148,7 @@ func (dg *DataGenerator) RandomBody(body pcommon.Value) {
 	switch dg.GenI64Range(0, 11) {
 	case 0:
 		// Body with a random string
-		body.SetStr(gofakeit.LoremIpsumSentence(20))
+		body.SetStr(dg.faker.LoremIpsumSentence(20))
 	case 1:
 		// Body an empty string
 		body.SetStr("")
@@ -158,13 +156,13 @@ func (dg *DataGenerator) RandomBody(body pcommon.Value) {
 		// Empty body
 	case 3:
 		// Body with a random int
-		body.SetInt(gofakeit.Int64())
+		body.SetInt(dg.faker.Int64())
 	case 4:
 		// Body with a random double
-		body.SetDouble(gofakeit.Float64())
+		body.SetDouble(dg.faker.Float64())
 	case 5:
 		// Body with a random bool
-		body.SetBool(gofakeit.Bool())
+		body.SetBool(dg.faker.Bool())
 	case 6:
 		// Body with a slice of random bytes
 		body.SetEmptyBytes().FromRaw(dg.GenId(10))
@@ -174,7 +172,7 @@ func (dg *DataGenerator) RandomBody(body pcommon.Value) {
 	case 8:
 		// Body with a random map
 		bodyMap := body.SetEmptyMap()
-		bodyMap.PutStr("attr1", gofakeit.LoremIpsumSentence(10))
+		bodyMap.PutStr("attr1", dg.faker.LoremIpsumSentence(10))
 		bodyMap.PutInt("attr2", 1)
 	case 9:
 		// Body with an empty map
@@ -182,8 +180,8 @@ func (dg *DataGenerator) RandomBody(body pcommon.Value) {
 	case 10:
 		// Body with a random slice
 		bodySlice := body.SetEmptySlice()
-		bodySlice.AppendEmpty().SetStr(gofakeit.LoremIpsumSentence(10))
-		bodySlice.AppendEmpty().SetInt(gofakeit.Int64())
+		bodySlice.AppendEmpty().SetStr(dg.faker.LoremIpsumSentence(10))
+		bodySlice.AppendEmpty().SetInt(dg.faker.Int64())
 	case 11:
 		// Body with an empty slice
 		body.SetEmptySlice()
@@ -323,6 +323,7 @@ func (dg *DataGenerator) SystemCpuTime(metric pmetric.Metric, cpuCount int) {
 			dataPoint.Attributes().PutStr("cpu_mhz", "2.4")
 			dataPoint.Attributes().PutStr("cpu_cores", "4")
 			dataPoint.Attributes().PutStr("cpu_logical_processors", "8")
+			dg.addRandomAttributes(dataPoint.Attributes())
 
 			dataPoint.SetStartTimestamp(dg.PrevTime())
 			dataPoint.SetTimestamp(dg.CurrentTime())
@@ -355,6 +356,7 @@ func (dg *DataGenerator) SystemMemoryUsage(metric pmetric.Metric) {
 	p1.Attributes().PutStr("cpu_id", "cpu-0")
 	p1.Attributes().PutStr("cpu_arch", "x86-64")
 	p1.Attributes().PutStr("cpu_vendor", "intel")
+	dg.addRandomAttributes(p1.Attributes())
 
 	p1.SetStartTimestamp(dg.PrevTime())
 	p1.SetTimestamp(dg.CurrentTime())
@@ -370,6 +372,7 @@ func (dg *DataGenerator) SystemMemoryUsage(metric pmetric.Metric) {
 	p2.Attributes().PutStr("cpu_vendor", "intel")
 	p2.Attributes().PutStr("cpu_model", "i7")
 	p2.Attributes().PutStr("cpu_mhz", "2.4")
+	dg.addRandomAttributes(p2.Attributes())
 	p2.SetStartTimestamp(dg.PrevTime())
 	p2.SetTimestamp(dg.CurrentTime())
 	p2.SetIntValue(dg.GenI64Range(300_000_000, 500_000_000))
@@ -384,6 +387,7 @@ func (dg *DataGenerator) SystemMemoryUsage(metric pmetric.Metric) {
 	p3.Attributes().PutStr("cpu_mhz", "2.4")
 	p3.Attributes().PutStr("cpu_cores", "4")
 	p3.Attributes().PutStr("cpu_logical_processors", "8")
+	dg.addRandomAttributes(p3.Attributes())
 	p3.SetStartTimestamp(dg.PrevTime())
 	p3.SetTimestamp(dg.CurrentTime())
 	p3.SetIntValue(4_000_000_000)
@@ -459,6 +463,7 @@ func (dg *DataGenerator) SumWithExemplars(metric pmetric.Metric) {
 		dataPoint.Attributes().PutStr("cpu_mhz", "2.4")
 		dataPoint.Attributes().PutStr("cpu_cores", "4")
 		dataPoint.Attributes().PutStr("cpu_logical_processors", "8")
+		dg.addRandomAttributes(dataPoint.Attributes())
 
 		dataPoint.SetStartTimestamp(dg.PrevTime())
 		dataPoint.SetTimestamp(dg.CurrentTime())
@@ -474,6 +479,7 @@ func (dg *DataGenerator) SumWithExemplars(metric pmetric.Metric) {
 			attrs.EnsureCapacity(2)
 			attrs.PutStr("freq", "3GHz")
 			attrs.PutInt("cpu", 0)
+			dg.addRandomAttributes(attrs)
 		}
 
 		dataPoint.SetFlags(pmetric.DataPointFlags(1))
@@ -546,6 +552,7 @@ func (dg *DataGenerator) GaugeWithExemplars(metric pmetric.Metric) {
 		dataPoint.Attributes().PutStr("cpu_mhz", "2.4")
 		dataPoint.Attributes().PutStr("cpu_cores", "4")
 		dataPoint.Attributes().PutStr("cpu_logical_processors", "8")
+		dg.addRandomAttributes(dataPoint.Attributes())
 
 		dataPoint.SetStartTimestamp(dg.PrevTime())
 		dataPoint.SetTimestamp(dg.CurrentTime())
@@ -561,6 +568,7 @@ func (dg *DataGenerator) GaugeWithExemplars(metric pmetric.Metric) {
 			attrs.EnsureCapacity(2)
 			attrs.PutStr("freq", "3GHz")
 			attrs.PutInt("cpu", 0)
+			dg.addRandomAttributes(attrs)
 		}
 
 		dataPoint.SetFlags(pmetric.DataPointFlags(1))
@@ -641,6 +649,7 @@ func (dg *DataGenerator) SystemCpuLoadAverage1m(metric pmetric.Metric) {
 	attrs.PutStr("cpu_mhz", "2.4")
 	attrs.PutStr("cpu_cores", "4")
 	attrs.PutStr("cpu_logical_processors", "8")
+	dg.addRandomAttributes(attrs)
 }
 
 func (dg *DataGenerator) FakeSummary(metric pmetric.Metric) {
@@ -674,6 +683,7 @@ func (dg *DataGenerator) FakeSummary(metric pmetric.Metric) {
 		attrs.PutStr("cpu_mhz", "2.4")
 		attrs.PutStr("cpu_cores", "4")
 		attrs.PutStr("cpu_logical_processors", "8")
+		dg.addRandomAttributes(attrs)
 
 		dp.SetCount(uint64(dg.GenI64Range(0, 100)))
 		dp.SetSum(dg.GenF64Range(0, 100))
@@ -724,6 +734,7 @@ func (dg *DataGenerator) FakeHistogram(metric pmetric.Metric) {
 		attrs.PutStr("cpu_mhz", "2.4")
 		attrs.PutStr("cpu_cores", "4")
 		attrs.PutStr("cpu_logical_processors", "8")
+		dg.addRandomAttributes(attrs)
 
 		dp.SetCount(uint64(dg.GenI64Range(0, 100)))
 		if dg.HasHistogramSum() {
@@ -781,6 +792,7 @@ func (dg *DataGenerator) ExpHistogramWithEverything(metric pmetric.Metric) {
 		attrs.PutStr("cpu_mhz", "2.4")
 		attrs.PutStr("cpu_cores", "4")
 		attrs.PutStr("cpu_logical_processors", "8")
+		dg.addRandomAttributes(attrs)
 
 		dp.SetCount(uint64(dg.GenI64Range(0, 100)))
 		dp.SetSum(dg.GenF64Range(0, 100))
@@ -813,6 +825,7 @@ func (dg *DataGenerator) ExpHistogramWithEverything(metric pmetric.Metric) {
 			attrs.EnsureCapacity(2)
 			attrs.PutStr("freq", "3GHz")
 			attrs.PutInt("cpu", 0)
+			dg.addRandomAttributes(attrs)
 		}
 
 		dp.SetMin(dg.GenF64Range(0, 100))
@@ -960,6 +973,7 @@ func (dg *DataGenerator) ExpHistogramWithOnlyExemplars(metric pmetric.Metric) {
 			attrs.EnsureCapacity(2)
 			attrs.PutStr("freq", "3GHz")
 			attrs.PutInt("cpu", 0)
+			dg.addRandomAttributes(attrs)
 		}
 	}
 }
@@ -994,6 +1008,7 @@ func (dg *DataGenerator) HistogramWithEverything(metric pmetric.Metric) {
 		attrs.PutStr("cpu_mhz", "2.4")
 		attrs.PutStr("cpu_cores", "4")
 		attrs.PutStr("cpu_logical_processors", "8")
+		dg.addRandomAttributes(attrs)
 
 		dp.SetCount(uint64(dg.GenI64Range(0, 100)))
 		dp.SetSum(dg.GenF64Range(0, 100))
@@ -1020,6 +1035,7 @@ func (dg *DataGenerator) HistogramWithEverything(metric pmetric.Metric) {
 			attrs.EnsureCapacity(2)
 			attrs.PutStr("freq", "3GHz")
 			attrs.PutInt("cpu", 0)
+			dg.addRandomAttributes(attrs)
 		}
 
 		dp.SetMin(dg.GenF64Range(0, 100))
@@ -1159,6 +1175,7 @@ func (dg *DataGenerator) HistogramWithOnlyExemplars(metric pmetric.Metric) {
 			attrs.EnsureCapacity(2)
 			attrs.PutStr("freq", "3GHz")
 			attrs.PutInt("cpu", 0)
+			dg.addRandomAttributes(attrs)
 		}
 	}
 }
@@ -18,7 +18,6 @@ import (
 	"fmt"
 	"time"
 
-	"github.com/brianvoe/gofakeit/v6"
 	"go.opentelemetry.io/collector/pdata/pcommon"
 	"go.opentelemetry.io/collector/pdata/ptrace"
 )
@@ -194,7 +193,7 @@ func (dg *DataGenerator) AddRandomSpansTo(spans ptrace.SpanSlice) {
 		span.SetParentSpanID(rootSpanId)
 	}
 	if dg.GenBool() {
-		span.TraceState().FromRaw(gofakeit.LoremIpsumWord())
+		span.TraceState().FromRaw(dg.faker.LoremIpsumWord())
 	}
 	if dg.GenBool() {
 		span.SetName("GET /user-info")
@@ -260,7 +259,7 @@ func (dg *DataGenerator) RandomEvents(ses ptrace.SpanEventSlice) {
 			event.SetTimestamp(dg.CurrentTime() + pcommon.Timestamp(dg.rng.Intn(5)))
 		}
 		if dg.GenBool() {
-			event.SetName(gofakeit.LoremIpsumWord())
+			event.SetName(dg.faker.LoremIpsumWord())
 		}
 		if dg.GenBool() {
 			attributes := dg.RandomAttributes()
@@ -297,7 +296,7 @@ func (dg *DataGenerator) RandomLinks(sls ptrace.SpanLinkSlice, traceID pcommon.T
 			sl.SetSpanID(spanID)
 		}
 		if dg.GenBool() {
-			sl.TraceState().FromRaw(gofakeit.LoremIpsumWord())
+			sl.TraceState().FromRaw(dg.faker.LoremIpsumWord())
 		}
 		if dg.GenBool() {
 			attributes := dg.RandomAttributes()
@@ -34,6 +34,7 @@ import (
 
 	colarspb "github.com/open-telemetry/otel-arrow/api/experimental/arrow/v1"
 	"github.com/open-telemetry/otel-arrow/pkg/internal/debug"
+	arrowotel "github.com/open-telemetry/otel-arrow/pkg/otel"
 	common "github.com/open-telemetry/otel-arrow/pkg/otel/common/arrow"
 	logsotlp "github.com/open-telemetry/otel-arrow/pkg/otel/logs/otlp"
 	metricsotlp "github.com/open-telemetry/otel-arrow/pkg/otel/metrics/otlp"
@@ -65,6 +66,10 @@ var ErrConsumerMemoryLimit = fmt.Errorf(
 		"Please increase the memory limit of the consumer.")
 
 // Consumer is a BatchArrowRecords consumer.
+//
+// Columns are resolved by name, so columns unknown to this consumer (e.g.
+// optional fields added by a newer producer) are always ignored; this is not
+// configurable. A missing mandatory column is reported as an error.
 type Consumer struct {
 	// streamConsumers is a map of reader state by SchemaID.
 	streamConsumers map[string]*streamConsumer
@@ -87,6 +92,8 @@ type Consumer struct {
 	recordsCounter metric.Int64Counter
 	// counts of the number of schema resets by data type.
 	schemaResetCounter metric.Int64Counter
+	// counts of the number of payloads skipped because their type is unknown.
+	unknownPayloadCounter metric.Int64Counter
 	// tracks allocator.Inuse()
 	memoryCounter metric.Int64UpDownCounter
 
@@ -101,6 +108,10 @@ type Config struct {
 
 	tracesConfig *arrow.Config
 
+	// strictPayloadTypes rejects BatchArrowRecords messages containing
+	// payload types unknown to this version of the protocol.
+	strictPayloadTypes bool
+
 	// from component.TelemetrySettings
 	meterProvider metric.MeterProvider
 	metricsLevel  configtelemetry.Level
@@ -120,6 +131,17 @@ func WithTracesConfig(tcfg *arrow.Config) Option {
 	}
 }
 
+// WithStrictPayloadTypes configures the consumer to reject
+// BatchArrowRecords messages containing a payload type unknown to this
+// version of the protocol with an otel.UnknownPayloadType error. By default,
+// such payloads are skipped, and counted by payload type, so that a consumer
+// remains compatible with newer producers.
+func WithStrictPayloadTypes() Option {
+	return func(cfg *Config) {
+		cfg.strictPayloadTypes = true
+	}
+}
+
 // WithMeterProvider configures an OTel metrics provider.  If none is
 // configured, the global meter provider will be used.
 func WithMeterProvider(p metric.MeterProvider, l configtelemetry.Level) Option {
@@ -156,19 +178,21 @@ func NewConsumer(opts ...Option) *Consumer {
 	allocator := common.NewLimitedAllocator(baseAlloc, cfg.memLimit)
 
 	c := &Consumer{
-		Config:             cfg,
-		allocator:          allocator,
-		uniqueAttr:         attribute.String("stream_unique", fmt.Sprintf("%08x", rand.Uint32())),
-		streamConsumers:    make(map[string]*streamConsumer),
-		recordsCounter:     noop.Int64Counter{},
-		schemaResetCounter: noop.Int64Counter{},
-		memoryCounter:      noop.Int64UpDownCounter{},
+		Config:                cfg,
+		allocator:             allocator,
+		uniqueAttr:            attribute.String("stream_unique", fmt.Sprintf("%08x", rand.Uint32())),
+		streamConsumers:       make(map[string]*streamConsumer),
+		recordsCounter:        noop.Int64Counter{},
+		schemaResetCounter:    noop.Int64Counter{},
+		unknownPayloadCounter: noop.Int64Counter{},
+		memoryCounter:         noop.Int64UpDownCounter{},
 	}
 	if cfg.metricsLevel >= configtelemetry.LevelNormal {
 		meter := cfg.meterProvider.Meter("otel-arrow/pkg/otel/arrow_record")
 
 		c.recordsCounter = mustWarn(meter.Int64Counter("arrow_batch_records"))
 		c.schemaResetCounter = mustWarn(meter.Int64Counter("arrow_schema_resets"))
+		c.unknownPayloadCounter = mustWarn(meter.Int64Counter("arrow_unknown_payloads"))
 		c.memoryCounter = mustWarn(meter.Int64UpDownCounter("arrow_memory_inuse"))
 	}
 	return c
@@ -269,6 +293,9 @@ func (c *Consumer) LogsFrom(bar *colarspb.BatchArrowRecords) ([]plog.Logs, error
 
 	// Compute all related records (i.e. Attributes)
 	relatedData, logsRecord, err := logsotlp.RelatedDataFrom(records)
+	if err != nil {
+		return nil, werror.Wrap(err)
+	}
 
 	if logsRecord != nil {
 		// Decode OTLP logs from the combination of the main record and the
@@ -295,6 +322,9 @@ func (c *Consumer) TracesFrom(bar *colarspb.BatchArrowRecords) ([]ptrace.Traces,
 
 	// Compute all related records (i.e. Attributes, Events, and Links)
 	relatedData, tracesRecord, err := tracesotlp.RelatedDataFrom(records, c.tracesConfig)
+	if err != nil {
+		return nil, werror.Wrap(err)
+	}
 
 	if tracesRecord != nil {
 		// Decode OTLP traces from the combination of the main record and the
@@ -314,13 +344,33 @@ func (c *Consumer) TracesFrom(bar *colarspb.BatchArrowRecords) ([]ptrace.Traces,
 func (c *Consumer) Consume(bar *colarspb.BatchArrowRecords) ([]*record_message.RecordMessage, error) {
 	ctx := context.Background()
 
+	if c.strictPayloadTypes {
+		for _, payload := range bar.ArrowPayloads {
+			if !isKnownPayloadType(payload.Type) {
+				return nil, werror.WrapWithContext(arrowotel.UnknownPayloadType, map[string]interface{}{
+					"payload_type": int32(payload.Type),
+					"schema_id":    payload.SchemaId,
+				})
+			}
+		}
+	}
+
 	var ibes []*record_message.RecordMessage
 	defer func() {
 		c.recordsCounter.Add(ctx, int64(len(ibes)), c.metricOpts()...)
 	}()
 
+	// Number of payloads ignored because their type is unknown.
+	skipped := 0
+
 	// Transform each individual OtlpArrowPayload into RecordMessage
 	for _, payload := range bar.ArrowPayloads {
+		if !isKnownPayloadType(payload.Type) {
+			c.unknownPayloadCounter.Add(ctx, 1, c.metricOpts(attribute.String("payload_type", payload.Type.String()))...)
+			skipped++
+			continue
+		}
+
 		// Retrieves (or creates) the stream consumer for the schema id defined in the BatchArrowRecords message.
 		sc := c.streamConsumers[payload.SchemaId]
 		if sc == nil {
@@ -372,7 +422,7 @@ func (c *Consumer) Consume(bar *colarspb.BatchArrowRecords) ([]*record_message.R
 		}
 	}
 
-	if len(ibes) < len(bar.ArrowPayloads) {
+	if len(ibes) < len(bar.ArrowPayloads)-skipped {
 		releaseRecords(ibes)
 		return nil, ErrConsumerMemoryLimit
 	}
@@ -380,6 +430,13 @@ func (c *Consumer) Consume(bar *colarspb.BatchArrowRecords) ([]*record_message.R
 	return ibes, nil
 }
 
+// isKnownPayloadType returns true if the payload type is defined by this
+// version of the protocol.
+func isKnownPayloadType(payloadType record_message.PayloadType) bool {
+	_, ok := colarspb.ArrowPayloadType_name[int32(payloadType)]
+	return ok && payloadType != colarspb.ArrowPayloadType_UNKNOWN
+}
+
 type runtimeChecker struct{}
 
 var _ memory.TestingT = &runtimeChecker{}
@@ -15,23 +15,36 @@
 package arrow_record
 
 import (
+	"bytes"
+	"context"
 	"encoding/json"
 	"fmt"
-	"math/rand"
+	"sort"
 	"testing"
 	"time"
 
+	"github.com/apache/arrow/go/v12/arrow/ipc"
 	"github.com/apache/arrow/go/v12/arrow/memory"
 	"github.com/stretchr/testify/require"
+	"go.opentelemetry.io/collector/config/configtelemetry"
+	"go.opentelemetry.io/collector/pdata/plog"
 	"go.opentelemetry.io/collector/pdata/plog/plogotlp"
+	"go.opentelemetry.io/collector/pdata/pmetric"
 	"go.opentelemetry.io/collector/pdata/pmetric/pmetricotlp"
+	"go.opentelemetry.io/collector/pdata/ptrace"
 	"go.opentelemetry.io/collector/pdata/ptrace/ptraceotlp"
+	"go.opentelemetry.io/otel/attribute"
+	"go.opentelemetry.io/otel/metric"
+	"go.opentelemetry.io/otel/metric/noop"
 	"google.golang.org/protobuf/proto"
 
 	arrowpb "github.com/open-telemetry/otel-arrow/api/experimental/arrow/v1"
 	"github.com/open-telemetry/otel-arrow/pkg/config"
 	"github.com/open-telemetry/otel-arrow/pkg/datagen"
+	"github.com/open-telemetry/otel-arrow/pkg/otel"
 	"github.com/open-telemetry/otel-arrow/pkg/otel/assert"
+	ocommon "github.com/open-telemetry/otel-arrow/pkg/otel/common"
+	"github.com/open-telemetry/otel-arrow/pkg/otel/internal"
 )
 
 // Fuzz-tests the consumer on a sequence of two OTLP protobuf inputs.
@@ -198,7 +211,7 @@ func FuzzProducerTraces1(f *testing.F) {
 }
 
 func TestProducerConsumerTraces(t *testing.T) {
-	ent := datagen.NewTestEntropy(int64(rand.Uint64())) //nolint:gosec // only used for testing
+	ent := datagen.NewTestEntropy(internal.TestSeed(t))
 
 	stdTesting := assert.NewStdUnitTest(t)
 
@@ -271,7 +284,7 @@ func TestProducerConsumerTraces(t *testing.T) {
 }
 
 func TestProducerConsumerLogs(t *testing.T) {
-	ent := datagen.NewTestEntropy(int64(rand.Uint64())) //nolint:gosec // only used for testing
+	ent := datagen.NewTestEntropy(internal.TestSeed(t))
 
 	stdTesting := assert.NewStdUnitTest(t)
 
@@ -325,7 +338,7 @@ func TestProducerConsumerLogs(t *testing.T) {
 }
 
 func TestProducerConsumerMetrics(t *testing.T) {
-	ent := datagen.NewTestEntropy(int64(rand.Uint64())) //nolint:gosec // only used for testing
+	ent := datagen.NewTestEntropy(internal.TestSeed(t))
 
 	stdTesting := assert.NewStdUnitTest(t)
 
@@ -393,3 +406,369 @@ func TestProducerConsumerMetrics(t *testing.T) {
 		})
 	}
 }
+
+// schemaUrlFixtures lists the (resource, scope) schema URL pairs used by
+// TestProducerConsumerSchemaUrl. The first two resources share the same
+// attributes but differ by schema URL, and each resource carries a distinct
+// scope schema URL.
+var schemaUrlFixtures = []struct {
+	service   string
+	resSchema string
+	scpSchema string
+}{
+	{"svc-a", "https://opentelemetry.io/schemas/1.20.0", "https://example.com/scope/a/1.0.0"},
+	{"svc-a", "https://opentelemetry.io/schemas/1.21.0", "https://example.com/scope/a/2.0.0"},
+	{"svc-b", "", "https://example.com/scope/b/1.0.0"},
+	{"svc-c", "https://opentelemetry.io/schemas/1.22.0", ""},
+}
+
+// expectedSchemaUrlPairs returns the sorted list of "resource|scope" schema
+// URL pairs of schemaUrlFixtures.
+func expectedSchemaUrlPairs() []string {
+	pairs := make([]string, 0, len(schemaUrlFixtures))
+	for _, fixture := range schemaUrlFixtures {
+		pairs = append(pairs, fixture.resSchema+"|"+fixture.scpSchema)
+	}
+	sort.Strings(pairs)
+	return pairs
+}
+
+// TestProducerConsumerSchemaUrl checks that resource and scope schema URLs
+// survive the OTLP -> OTel Arrow -> OTLP round trip for every signal.
+func TestProducerConsumerSchemaUrl(t *testing.T) {
+	stdTesting := assert.NewStdUnitTest(t)
+
+	t.Run("logs", func(t *testing.T) {
+		logs := plog.NewLogs()
+		for _, fixture := range schemaUrlFixtures {
+			rl := logs.ResourceLogs().AppendEmpty()
+			rl.SetSchemaUrl(fixture.resSchema)
+			rl.Resource().Attributes().PutStr("service.name", fixture.service)
+			sl := rl.ScopeLogs().AppendEmpty()
+			sl.SetSchemaUrl(fixture.scpSchema)
+			sl.Scope().SetName("scope")
+			sl.LogRecords().AppendEmpty().Body().SetStr("body")
+		}
+
+		producer := NewProducer()
+		defer func() {
+			require.NoError(t, producer.Close())
+		}()
+
+		batch, err := producer.BatchArrowRecordsFromLogs(logs)
+		require.NoError(t, err)
+
+		received, err := NewConsumer().LogsFrom(batch)
+		require.NoError(t, err)
+		require.Equal(t, 1, len(received))
+
+		assert.Equiv(
+			stdTesting,
+			[]json.Marshaler{plogotlp.NewExportRequestFromLogs(logs)},
+			[]json.Marshaler{plogotlp.NewExportRequestFromLogs(received[0])},
+		)
+
+		var pairs []string
+		rls := received[0].ResourceLogs()
+		for i := 0; i < rls.Len(); i++ {
+			sls := rls.At(i).ScopeLogs()
+			for j := 0; j < sls.Len(); j++ {
+				pairs = append(pairs, rls.At(i).SchemaUrl()+"|"+sls.At(j).SchemaUrl())
+			}
+		}
+		sort.Strings(pairs)
+		require.Equal(t, expectedSchemaUrlPairs(), pairs)
+	})
+
+	t.Run("traces", func(t *testing.T) {
+		traces := ptrace.NewTraces()
+		for _, fixture := range schemaUrlFixtures {
+			rs := traces.ResourceSpans().AppendEmpty()
+			rs.SetSchemaUrl(fixture.resSchema)
+			rs.Resource().Attributes().PutStr("service.name", fixture.service)
+			ss := rs.ScopeSpans().AppendEmpty()
+			ss.SetSchemaUrl(fixture.scpSchema)
+			ss.Scope().SetName("scope")
+			ss.Spans().AppendEmpty().SetName("span")
+		}
+
+		producer := NewProducer()
+		defer func() {
+			require.NoError(t, producer.Close())
+		}()
+
+		batch, err := producer.BatchArrowRecordsFromTraces(traces)
+		require.NoError(t, err)
+
+		received, err := NewConsumer().TracesFrom(batch)
+		require.NoError(t, err)
+		require.Equal(t, 1, len(received))
+
+		assert.Equiv(
+			stdTesting,
+			[]json.Marshaler{ptraceotlp.NewExportRequestFromTraces(traces)},
+			[]json.Marshaler{ptraceotlp.NewExportRequestFromTraces(received[0])},
+		)
+
+		var pairs []string
+		rss := received[0].ResourceSpans()
+		for i := 0; i < rss.Len(); i++ {
+			sss := rss.At(i).ScopeSpans()
+			for j := 0; j < sss.Len(); j++ {
+				pairs = append(pairs, rss.At(i).SchemaUrl()+"|"+sss.At(j).SchemaUrl())
+			}
+		}
+		sort.Strings(pairs)
+		require.Equal(t, expectedSchemaUrlPairs(), pairs)
+	})
+
+	t.Run("metrics", func(t *testing.T) {
+		metrics := pmetric.NewMetrics()
+		for _, fixture := range schemaUrlFixtures {
+			rm := metrics.ResourceMetrics().AppendEmpty()
+			rm.SetSchemaUrl(fixture.resSchema)
+			rm.Resource().Attributes().PutStr("service.name", fixture.service)
+			sm := rm.ScopeMetrics().AppendEmpty()
+			sm.SetSchemaUrl(fixture.scpSchema)
+			sm.Scope().SetName("scope")
+			m := sm.Metrics().AppendEmpty()
+			m.SetName("gauge")
+			m.SetEmptyGauge().DataPoints().AppendEmpty().SetIntValue(1)
+		}
+
+		producer := NewProducer()
+		defer func() {
+			require.NoError(t, producer.Close())
+		}()
+
+		batch, err := producer.BatchArrowRecordsFromMetrics(metrics)
+		require.NoError(t, err)
+
+		received, err := NewConsumer().MetricsFrom(batch)
+		require.NoError(t, err)
+		require.Equal(t, 1, len(received))
+
+		assert.Equiv(
+			stdTesting,
+			[]json.Marshaler{pmetricotlp.NewExportRequestFromMetrics(metrics)},
+			[]json.Marshaler{pmetricotlp.NewExportRequestFromMetrics(received[0])},
+		)
+
+		var pairs []string
+		rms := received[0].ResourceMetrics()
+		for i := 0; i < rms.Len(); i++ {
+			sms := rms.At(i).ScopeMetrics()
+			for j := 0; j < sms.Len(); j++ {
+				pairs = append(pairs, rms.At(i).SchemaUrl()+"|"+sms.At(j).SchemaUrl())
+			}
+		}
+		sort.Strings(pairs)
+		require.Equal(t, expectedSchemaUrlPairs(), pairs)
+	})
+}
+
+// TestConsumerUnknownPayloadType checks that payloads of a type unknown to
+// the consumer are ignored by default and rejected in strict mode.
+func TestConsumerUnknownPayloadType(t *testing.T) {
+	ent := datagen.NewTestEntropy(internal.TestSeed(t))
+	dg := datagen.NewLogsGenerator(
+		ent,
+		ent.NewStandardResourceAttributes(),
+		ent.NewStandardInstrumentationScopes(),
+	)
+	logs := dg.Generate(10, time.Minute)
+
+	// newBatch returns a logs batch followed by a payload whose type is
+	// not defined by the protocol, e.g. emitted by a newer producer.
+	newBatch := func(t *testing.T) *arrowpb.BatchArrowRecords {
+		producer := NewProducer()
+		defer func() {
+			require.NoError(t, producer.Close())
+		}()
+
+		batch, err := producer.BatchArrowRecordsFromLogs(logs)
+		require.NoError(t, err)
+
+		batch.ArrowPayloads = append(batch.ArrowPayloads, &arrowpb.ArrowPayload{
+			SchemaId: "unknown",
+			Type:     arrowpb.ArrowPayloadType(1000),
+			Record:   batch.ArrowPayloads[0].Record,
+		})
+		return batch
+	}
+
+	t.Run("lenient", func(t *testing.T) {
+		meterProvider := &countingMeterProvider{counts: make(map[string]int64)}
+		consumer := NewConsumer(WithMeterProvider(meterProvider, configtelemetry.LevelNormal))
+		defer func() {
+			require.NoError(t, consumer.Close())
+		}()
+
+		received, err := consumer.LogsFrom(newBatch(t))
+		require.NoError(t, err)
+		require.Equal(t, 1, len(received))
+		require.Equal(t, int64(1), meterProvider.counts["arrow_unknown_payloads{payload_type=1000}"])
+
+		assert.Equiv(
+			assert.NewStdUnitTest(t),
+			[]json.Marshaler{plogotlp.NewExportRequestFromLogs(logs)},
+			[]json.Marshaler{plogotlp.NewExportRequestFromLogs(received[0])},
+		)
+	})
+
+	t.Run("strict", func(t *testing.T) {
+		consumer := NewConsumer(WithStrictPayloadTypes())
+		defer func() {
+			require.NoError(t, consumer.Close())
+		}()
+
+		_, err := consumer.LogsFrom(newBatch(t))
+		require.ErrorIs(t, err, otel.UnknownPayloadType)
+		require.Contains(t, err.Error(), "payload_type=1000")
+	})
+}
+
+// countingMeterProvider is a metric.MeterProvider summing the increments of
+// its Int64Counters by instrument name and attributes, e.g.
+// `arrow_schema_resets{payload_type=LOGS}`.
+type countingMeterProvider struct {
+	noop.MeterProvider
+	counts map[string]int64
+}
+
+func (p *countingMeterProvider) Meter(string, ...metric.MeterOption) metric.Meter {
+	return countingMeter{provider: p}
+}
+
+type countingMeter struct {
+	noop.Meter
+	provider *countingMeterProvider
+}
+
+func (m countingMeter) Int64Counter(name string, _ ...metric.Int64CounterOption) (metric.Int64Counter, error) {
+	return countingCounter{name: name, provider: m.provider}, nil
+}
+
+type countingCounter struct {
+	noop.Int64Counter
+	name     string
+	provider *countingMeterProvider
+}
+
+func (c countingCounter) Add(_ context.Context, incr int64, opts ...metric.AddOption) {
+	attrs := metric.NewAddConfig(opts).Attributes()
+	c.provider.counts[c.name+"{"+attrs.Encoded(attribute.DefaultEncoder())+"}"] += incr
+}
+
+// withUnknownColumns rewrites every payload of the batch as if it had been
+// emitted by a newer producer adding an optional column unknown to this
+// consumer. Each payload is re-encoded as a new IPC stream under a new
+// schema ID.
+func withUnknownColumns(t *testing.T, batch *arrowpb.BatchArrowRecords) {
+	pool := memory.NewGoAllocator()
+
+	for _, payload := range batch.ArrowPayloads {
+		reader, err := ipc.NewReader(bytes.NewReader(payload.Record), ipc.WithAllocator(pool))
+		require.NoError(t, err)
+		require.True(t, reader.Next())
+
+		record := ocommon.AppendUnknownColumn(pool, reader.Record())
+
+		var buf bytes.Buffer
+		writer := ipc.NewWriter(&buf, ipc.WithAllocator(pool), ipc.WithSchema(record.Schema()))
+		require.NoError(t, writer.Write(record))
+		require.NoError(t, writer.Close())
+		record.Release()
+		reader.Release()
+
+		payload.SchemaId += ":unknown_column"
+		payload.Record = buf.Bytes()
+	}
+}
+
+// TestConsumerUnknownColumns checks that the consumer decodes batches whose
+// records (main and related) carry an extra column unknown to it, as a newer
+// producer adding an optional field would emit. Ignoring unknown columns is
+// not configurable: the decoders always resolve columns by name.
+func TestConsumerUnknownColumns(t *testing.T) {
+	ent := datagen.NewTestEntropy(internal.TestSeed(t))
+	stdTesting := assert.NewStdUnitTest(t)
+
+	t.Run("logs", func(t *testing.T) {
+		logs := datagen.NewLogsGenerator(
+			ent,
+			ent.NewStandardResourceAttributes(),
+			ent.NewStandardInstrumentationScopes(),
+		).Generate(10, time.Minute)
+
+		producer := NewProducer()
+		defer func() {
+			require.NoError(t, producer.Close())
+		}()
+		batch, err := producer.BatchArrowRecordsFromLogs(logs)
+		require.NoError(t, err)
+		withUnknownColumns(t, batch)
+
+		received, err := NewConsumer().LogsFrom(batch)
+		require.NoError(t, err)
+		require.Equal(t, 1, len(received))
+
+		assert.Equiv(
+			stdTesting,
+			[]json.Marshaler{plogotlp.NewExportRequestFromLogs(logs)},
+			[]json.Marshaler{plogotlp.NewExportRequestFromLogs(received[0])},
+		)
+	})
+
+	t.Run("traces", func(t *testing.T) {
+		traces := datagen.NewTracesGenerator(
+			ent,
+			ent.NewStandardResourceAttributes(),
+			ent.NewStandardInstrumentationScopes(),
+		).Generate(10, time.Minute)
+
+		producer := NewProducer()
+		defer func() {
+			require.NoError(t, producer.Close())
+		}()
+		batch, err := producer.BatchArrowRecordsFromTraces(traces)
+		require.NoError(t, err)
+		withUnknownColumns(t, batch)
+
+		received, err := NewConsumer().TracesFrom(batch)
+		require.NoError(t, err)
+		require.Equal(t, 1, len(received))
+
+		assert.Equiv(
+			stdTesting,
+			[]json.Marshaler{ptraceotlp.NewExportRequestFromTraces(traces)},
+			[]json.Marshaler{ptraceotlp.NewExportRequestFromTraces(received[0])},
+		)
+	})
+
+	t.Run("metrics", func(t *testing.T) {
+		metrics := datagen.NewMetricsGenerator(
+			ent,
+			ent.NewStandardResourceAttributes(),
+			ent.NewStandardInstrumentationScopes(),
+		).GenerateAllKindOfMetrics(10, time.Minute)
+
+		producer := NewProducer()
+		defer func() {
+			require.NoError(t, producer.Close())
+		}()
+		batch, err := producer.BatchArrowRecordsFromMetrics(metrics)
+		require.NoError(t, err)
+		withUnknownColumns(t, batch)
+
+		received, err := NewConsumer().MetricsFrom(batch)
+		require.NoError(t, err)
+		require.Equal(t, 1, len(received))
+
+		assert.Equiv(
+			stdTesting,
+			[]json.Marshaler{pmetricotlp.NewExportRequestFromMetrics(metrics)},
+			[]json.Marshaler{pmetricotlp.NewExportRequestFromMetrics(received[0])},
+		)
+	})
+}
@@ -128,22 +128,9 @@ func Equiv(t Testing, expected []json.Marshaler, actual []json.Marshaler) {
 		t.FailNow("Failed to convert actual traces to canonical representation", err)
 	}
 
-	missingExpectedVPaths := difference(expectedVPaths, actualVPaths)
-	missingActualVPaths := difference(actualVPaths, expectedVPaths)
+	if missing, unexpected := diffVPaths(expectedVPaths, actualVPaths); len(missing) > 0 || len(unexpected) > 0 {
+		printVPathsDiff(missing, unexpected)
 
-	if len(missingExpectedVPaths) > 0 {
-		fmt.Printf("Missing expected vPaths:\n")
-		for _, vPath := range missingExpectedVPaths {
-			fmt.Printf("+ %s\n", vPath)
-		}
-	}
-	if len(missingActualVPaths) > 0 {
-		fmt.Printf("Unexpected vPaths:\n")
-		for _, vPath := range missingActualVPaths {
-			fmt.Printf("- %s\n", vPath)
-		}
-	}
-	if len(missingExpectedVPaths) > 0 || len(missingActualVPaths) > 0 {
 		// To debug the difference between the expected and actual json objects,
 		// uncomment the following lines to print the expected and actual json
 		// objects.
@@ -167,24 +154,37 @@ func EquivFromBytes(t Testing, expected []byte, actual []byte) {
 		t.FailNow("Failed to convert actual traces to canonical representation", err)
 	}
 
-	missingExpectedVPaths := difference(expectedVPaths, actualVPaths)
-	missingActualVPaths := difference(actualVPaths, expectedVPaths)
+	if missing, unexpected := diffVPaths(expectedVPaths, actualVPaths); len(missing) > 0 || len(unexpected) > 0 {
+		printVPathsDiff(missing, unexpected)
+		t.FailNow("Traces are not equivalent")
+	}
+}
+
+// diffVPaths returns the sorted expected vPaths absent from the actual vPaths
+// and the sorted actual vPaths absent from the expected vPaths.
+func diffVPaths(expectedVPaths, actualVPaths []string) (missing []string, unexpected []string) {
+	missing = difference(expectedVPaths, actualVPaths)
+	unexpected = difference(actualVPaths, expectedVPaths)
+	sort.Strings(missing)
+	sort.Strings(unexpected)
+	return missing, unexpected
+}
 
-	if len(missingExpectedVPaths) > 0 {
+// printVPathsDiff prints the missing expected vPaths prefixed by `+` and the
+// unexpected vPaths prefixed by `-`.
+func printVPathsDiff(missing, unexpected []string) {
+	if len(missing) > 0 {
 		fmt.Printf("Missing expected vPaths:\n")
-		for _, vPath := range missingExpectedVPaths {
+		for _, vPath := range missing {
 			fmt.Printf("+ %s\n", vPath)
 		}
 	}
-	if len(missingActualVPaths) > 0 {
+	if len(unexpected) > 0 {
 		fmt.Printf("Unexpected vPaths:\n")
-		for _, vPath := range missingActualVPaths {
+		for _, vPath := range unexpected {
 			fmt.Printf("- %s\n", vPath)
 		}
 	}
-	if len(missingExpectedVPaths) > 0 || len(missingActualVPaths) > 0 {
-		t.FailNow("Traces are not equivalent")
-	}
 }
 
 // NotEquiv asserts that two arrays of json.Marshaler are not equivalent. See Equiv for the definition of equivalence.
@@ -199,10 +199,7 @@ func NotEquiv(t Testing, expected []json.Marshaler, actual []json.Marshaler) {
 		t.FailNow("Failed to convert actual traces to canonical representation", err)
 	}
 
-	missingExpectedVPaths := difference(expectedVPaths, actualVPaths)
-	missingActualVPaths := difference(actualVPaths, expectedVPaths)
-
-	if len(missingExpectedVPaths) == 0 && len(missingActualVPaths) == 0 {
+	if missing, unexpected := diffVPaths(expectedVPaths, actualVPaths); len(missing) == 0 && len(unexpected) == 0 {
 		t.FailNow("Traces should not be equivalent")
 	}
 }
@@ -288,18 +285,30 @@ func exportAllVPaths(traces map[string]interface{}, currentVPath string, vPaths
 			vPaths[localVPath+"="+strings.Join(strings.Fields(fmt.Sprint(v)), ",")] = true
 		case map[string]interface{}:
 			exportAllVPaths(v, localVPath, vPaths)
-		case string:
-			vPaths[localVPath+"="+v] = true
-		case int64:
-			vPaths[localVPath+"="+fmt.Sprintf("%d", v)] = true
-		case float64:
-			vPaths[localVPath+"="+fmt.Sprintf("%f", v)] = true
-		case bool:
-			vPaths[localVPath+"="+fmt.Sprintf("%f", 123.456)] = true
+		default:
+			if scalar, ok := scalarVPathValue(v); ok {
+				vPaths[localVPath+"="+scalar] = true
+			}
 		}
 	}
 }
 
+// scalarVPathValue returns the string representation of a scalar value in a
+// vPath.
+func scalarVPathValue(value interface{}) (string, bool) {
+	switch v := value.(type) {
+	case string:
+		return v, true
+	case int64:
+		return fmt.Sprintf("%d", v), true
+	case float64:
+		return fmt.Sprintf("%f", v), true
+	case bool:
+		return fmt.Sprintf("%f", 123.456), true
+	}
+	return "", false
+}
+
 // nonPositionalIndex returns a string that can be used to identify: resource,
 // scope, event, link, attribute, span, metrics, dataPoints.
 //
@@ -0,0 +1,417 @@
+// Copyright The OpenTelemetry Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package assert
+
+import (
+	"encoding/json"
+	"fmt"
+	"sort"
+	"strconv"
+	"strings"
+)
+
+// recordKeys lists the arrays whose items are diffed as records, i.e. the
+// items of both sides are paired before their fields are compared.
+var recordKeys = map[string]bool{
+	"resourceMetrics": true,
+	"resourceLogs":    true,
+	"resourceSpans":   true,
+	"scopeMetrics":    true,
+	"scopeLogs":       true,
+	"scopeSpans":      true,
+	"metrics":         true,
+	"dataPoints":      true,
+	"logRecords":      true,
+	"spans":           true,
+}
+
+// EquivDiff describes why two arrays of json.Marshaler are not equivalent
+// (see Equiv).
+//
+// Records (resources, scopes, metrics, data points, log records and spans)
+// are named by their positional path in the data they come from, e.g.
+// `resourceSpans[0].scopeSpans[0].spans[1]`. Attributes are named by their
+// key, e.g. `attributes[service.name].value.stringValue`. When several
+// records are equivalent (and therefore merged, see Equiv), the position of
+// the first one is used.
+type EquivDiff struct {
+	// Changed contains the fields of the records present on both sides whose
+	// values differ, sorted by record and field.
+	Changed []FieldDiff
+	// Missing contains the sorted expected vPaths of the records and fields
+	// absent from the actual data.
+	Missing []string
+	// Unexpected contains the sorted actual vPaths of the records and fields
+	// absent from the expected data.
+	Unexpected []string
+}
+
+// FieldDiff describes a field whose value differs between a record of the
+// expected data and the matching record of the actual data.
+type FieldDiff struct {
+	// Record is the positional path of the record in the expected data.
+	Record string
+	// ActualRecord is the positional path of the record in the actual data.
+	ActualRecord string
+	// Field is the path of the field relative to the record.
+	Field string
+	// Expected and Actual are the values of the field on each side. Several
+	// values are separated by a comma.
+	Expected string
+	Actual   string
+}
+
+// DiffEquiv is a non-failing variant of Equiv. It returns nil if expected and
+// actual are equivalent, and an EquivDiff otherwise. This is useful to collect
+// and report differences without interrupting the caller.
+//
+// The records of both sides are matched by content (see Equiv). A record
+// found on one side only is paired with the unmatched record of the other
+// side sharing the most field values, so that a record differing by a single
+// field is reported as one FieldDiff rather than as a missing and an
+// unexpected record.
+func DiffEquiv(expected []json.Marshaler, actual []json.Marshaler) (*EquivDiff, error) {
+	expectedVPaths, err := vPaths(expected)
+	if err != nil {
+		return nil, fmt.Errorf("failed to convert expected data to canonical representation: %w", err)
+	}
+	actualVPaths, err := vPaths(actual)
+	if err != nil {
+		return nil, fmt.Errorf("failed to convert actual data to canonical representation: %w", err)
+	}
+	if missing, unexpected := diffVPaths(expectedVPaths, actualVPaths); len(missing) == 0 && len(unexpected) == 0 {
+		return nil, nil
+	}
+
+	expectedRoot, err := diffTree(expected)
+	if err != nil {
+		return nil, fmt.Errorf("failed to convert expected data to canonical representation: %w", err)
+	}
+	actualRoot, err := diffTree(actual)
+	if err != nil {
+		return nil, fmt.Errorf("failed to convert actual data to canonical representation: %w", err)
+	}
+
+	diff := &EquivDiff{}
+	diff.diffNodes(expectedRoot, actualRoot)
+
+	sort.Slice(diff.Changed, func(i, j int) bool {
+		if diff.Changed[i].Record != diff.Changed[j].Record {
+			return diff.Changed[i].Record < diff.Changed[j].Record
+		}
+		return diff.Changed[i].Field < diff.Changed[j].Field
+	})
+	sort.Strings(diff.Missing)
+	sort.Strings(diff.Unexpected)
+
+	return diff, nil
+}
+
+// String returns the changed fields prefixed by `~`, the missing expected
+// vPaths prefixed by `+` and the unexpected vPaths prefixed by `-`.
+func (d *EquivDiff) String() string {
+	var sb strings.Builder
+	if len(d.Changed) > 0 {
+		sb.WriteString("Changed fields:\n")
+		for _, field := range d.Changed {
+			sb.WriteString(fmt.Sprintf("~ %s: expected %q, actual %q\n", joinPath(field.Record, field.Field), field.Expected, field.Actual))
+		}
+	}
+	if len(d.Missing) > 0 {
+		sb.WriteString("Missing expected vPaths:\n")
+		for _, vPath := range d.Missing {
+			sb.WriteString("+ " + vPath + "\n")
+		}
+	}
+	if len(d.Unexpected) > 0 {
+		sb.WriteString("Unexpected vPaths:\n")
+		for _, vPath := range d.Unexpected {
+			sb.WriteString("- " + vPath + "\n")
+		}
+	}
+	return sb.String()
+}
+
+// diffNode is a record (or the root of the data) with its fields and its child
+// records.
+type diffNode struct {
+	// pos is the positional path of the record.
+	pos string
+	// fields maps the path of each field, relative to the record, to its set
+	// of values.
+	fields map[string]map[string]bool
+	// children maps the path of each array of records, relative to the
+	// record, to its records indexed by non-positional index.
+	children map[string]map[string]*diffNode
+}
+
+func newDiffNode(pos string) *diffNode {
+	return &diffNode{
+		pos:      pos,
+		fields:   make(map[string]map[string]bool),
+		children: make(map[string]map[string]*diffNode),
+	}
+}
+
+// diffTree merges the json representation of the given marshalers into a
+// single tree of records.
+func diffTree(marshaler []json.Marshaler) (*diffNode, error) {
+	jsonObjects, err := jsonify(marshaler)
+	if err != nil {
+		return nil, err
+	}
+	root := newDiffNode("")
+	for _, jsonObject := range jsonObjects {
+		root.add(jsonObject, "")
+	}
+	return root, nil
+}
+
+// add adds the fields and child records of the json object located at the
+// relative path `rel` of the record.
+func (n *diffNode) add(object map[string]interface{}, rel string) {
+	for key, value := range object {
+		field := joinPath(rel, key)
+		switch v := value.(type) {
+		case []interface{}:
+			indices := itemIndices(v)
+			for i, item := range v {
+				vMap, ok := item.(map[string]interface{})
+				if !ok {
+					n.addField(fmt.Sprintf("%s[%d]", field, i), fmt.Sprint(item))
+					continue
+				}
+				if recordKeys[key] {
+					n.child(field, key, vMap, i).add(vMap, "")
+				} else {
+					n.add(vMap, field+"["+indices[i]+"]")
+				}
+			}
+		case map[string]interface{}:
+			n.add(v, field)
+		default:
+			if scalar, ok := scalarVPathValue(v); ok {
+				n.addField(field, scalar)
+			}
+		}
+	}
+}
+
+func (n *diffNode) addField(field string, value string) {
+	values, ok := n.fields[field]
+	if !ok {
+		values = make(map[string]bool)
+		n.fields[field] = values
+	}
+	values[value] = true
+}
+
+// child returns the child record identified by the non-positional index of
+// vMap, creating it at position i of the array `field` if needed.
+func (n *diffNode) child(field string, key string, vMap map[string]interface{}, i int) *diffNode {
+	records, ok := n.children[field]
+	if !ok {
+		records = make(map[string]*diffNode)
+		n.children[field] = records
+	}
+	index := nonPositionalIndex(key, vMap)
+	if index != "_" {
+		index = md5Hash(index)
+	}
+	record, ok := records[index]
+	if !ok {
+		record = newDiffNode(fmt.Sprintf("%s[%d]", joinPath(n.pos, field), i))
+		records[index] = record
+	}
+	return record
+}
+
+// itemIndices returns the indices used in the field paths of the items of
+// a non-record array. Items with a string `key` (e.g. attributes) are indexed
+// by key, the other ones by their rank by signature so that the indices don't
+// depend on the order of the items.
+func itemIndices(items []interface{}) []string {
+	indices := make([]string, len(items))
+	ranks := make([]int, len(items))
+	sigs := make([]string, len(items))
+	for i, item := range items {
+		ranks[i] = i
+		sigs[i] = sig(item)
+	}
+	sort.SliceStable(ranks, func(i, j int) bool {
+		return sigs[ranks[i]] < sigs[ranks[j]]
+	})
+	for rank, i := range ranks {
+		indices[i] = strconv.Itoa(rank)
+	}
+	for i, item := range items {
+		if vMap, ok := item.(map[string]interface{}); ok {
+			if key, ok := vMap["key"].(string); ok {
+				indices[i] = key
+			}
+		}
+	}
+	return indices
+}
+
+// diffNodes records the differences between two matching records.
+func (d *EquivDiff) diffNodes(expected, actual *diffNode) {
+	for _, field := range unionKeys(expected.fields, actual.fields) {
+		expectedValues, actualValues := expected.fields[field], actual.fields[field]
+		if sameValues(expectedValues, actualValues) {
+			continue
+		}
+		switch {
+		case len(expectedValues) > 0 && len(actualValues) > 0:
+			d.Changed = append(d.Changed, FieldDiff{
+				Record:       expected.pos,
+				ActualRecord: actual.pos,
+				Field:        field,
+				Expected:     strings.Join(sortedValues(expectedValues), ","),
+				Actual:       strings.Join(sortedValues(actualValues), ","),
+			})
+		case len(expectedValues) > 0:
+			d.Missing = appendFieldVPaths(d.Missing, expected.pos, field, expectedValues)
+		default:
+			d.Unexpected = appendFieldVPaths(d.Unexpected, actual.pos, field, actualValues)
+		}
+	}
+
+	for _, field := range unionKeys(expected.children, actual.children) {
+		d.diffRecords(expected.children[field], actual.children[field])
+	}
+}
+
+// diffRecords pairs the records of two arrays and records their differences.
+// Records with the same non-positional index are paired first, then each
+// remaining expected record is paired with the remaining actual record sharing
+// the most field values. The records left over are missing or unexpected.
+func (d *EquivDiff) diffRecords(expected, actual map[string]*diffNode) {
+	var unmatchedExpected, unmatchedActual []*diffNode
+
+	for index, expectedRecord := range expected {
+		if actualRecord, ok := actual[index]; ok {
+			d.diffNodes(expectedRecord, actualRecord)
+		} else {
+			unmatchedExpected = append(unmatchedExpected, expectedRecord)
+		}
+	}
+	for index, actualRecord := range actual {
+		if _, ok := expected[index]; !ok {
+			unmatchedActual = append(unmatchedActual, actualRecord)
+		}
+	}
+	sort.Slice(unmatchedExpected, func(i, j int) bool { return unmatchedExpected[i].pos < unmatchedExpected[j].pos })
+	sort.Slice(unmatchedActual, func(i, j int) bool { return unmatchedActual[i].pos < unmatchedActual[j].pos })
+
+	for _, expectedRecord := range unmatchedExpected {
+		best, bestOverlap := -1, 0
+		for i, actualRecord := range unmatchedActual {
+			if overlap := fieldOverlap(expectedRecord, actualRecord); overlap > bestOverlap {
+				best, bestOverlap = i, overlap
+			}
+		}
+		// Two records sharing no field value are only paired when each side
+		// has a single unmatched record.
+		if best < 0 && len(unmatchedActual) == 1 && len(unmatchedExpected) == 1 {
+			best = 0
+		}
+		if best < 0 {
+			d.Missing = appendRecordVPaths(d.Missing, expectedRecord)
+			continue
+		}
+		d.diffNodes(expectedRecord, unmatchedActual[best])
+		unmatchedActual = append(unmatchedActual[:best], unmatchedActual[best+1:]...)
+	}
+	for _, actualRecord := range unmatchedActual {
+		d.Unexpected = appendRecordVPaths(d.Unexpected, actualRecord)
+	}
+}
+
+// fieldOverlap returns the number of field values shared by two records.
+func fieldOverlap(a, b *diffNode) int {
+	overlap := 0
+	for field, aValues := range a.fields {
+		for value := range aValues {
+			if b.fields[field][value] {
+				overlap++
+			}
+		}
+	}
+	return overlap
+}
+
+// appendRecordVPaths appends the vPaths of all the fields of a record and of
+// its child records.
+func appendRecordVPaths(vPaths []string, record *diffNode) []string {
+	for field, values := range record.fields {
+		vPaths = appendFieldVPaths(vPaths, record.pos, field, values)
+	}
+	for _, records := range record.children {
+		for _, child := range records {
+			vPaths = appendRecordVPaths(vPaths, child)
+		}
+	}
+	return vPaths
+}
+
+func appendFieldVPaths(vPaths []string, pos string, field string, values map[string]bool) []string {
+	for _, value := range sortedValues(values) {
+		vPaths = append(vPaths, joinPath(pos, field)+"="+value)
+	}
+	return vPaths
+}
+
+func sameValues(a, b map[string]bool) bool {
+	if len(a) != len(b) {
+		return false
+	}
+	for value := range a {
+		if !b[value] {
+			return false
+		}
+	}
+	return true
+}
+
+func sortedValues(values map[string]bool) []string {
+	sorted := make([]string, 0, len(values))
+	for value := range values {
+		sorted = append(sorted, value)
+	}
+	sort.Strings(sorted)
+	return sorted
+}
+
+func unionKeys[V any](a, b map[string]V) []string {
+	keys := make([]string, 0, len(a)+len(b))
+	for key := range a {
+		keys = append(keys, key)
+	}
+	for key := range b {
+		if _, ok := a[key]; !ok {
+			keys = append(keys, key)
+		}
+	}
+	sort.Strings(keys)
+	return keys
+}
+
+func joinPath(prefix, suffix string) string {
+	if prefix == "" {
+		return suffix
+	}
+	return prefix + "." + suffix
+}
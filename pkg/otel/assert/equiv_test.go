@@ -17,9 +17,11 @@ package assert
 import (
 	"encoding/hex"
 	"encoding/json"
+	"strings"
 	"testing"
 
 	"github.com/zeebo/assert"
+	"go.opentelemetry.io/collector/pdata/pcommon"
 	"go.opentelemetry.io/collector/pdata/ptrace"
 	"go.opentelemetry.io/collector/pdata/ptrace/ptraceotlp"
 )
@@ -57,6 +59,76 @@ func TestEquiv(t *testing.T) {
 	NotEquiv(stdTesting, expectedTraces, actualTraces)
 }
 
+func TestDiffEquiv(t *testing.T) {
+	t.Parallel()
+
+	traces := ptrace.NewTraces()
+	rs := traces.ResourceSpans().AppendEmpty()
+	rs.Resource().Attributes().PutStr("foo", "bar")
+	spans := rs.ScopeSpans().AppendEmpty().Spans()
+	for i, name := range []string{"span1", "other"} {
+		span := spans.AppendEmpty()
+		span.SetName(name)
+		span.SetKind(ptrace.SpanKindServer)
+		span.SetTraceID(pcommon.TraceID([16]byte{1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16}))
+		span.SetSpanID(pcommon.SpanID([8]byte{1, 2, 3, 4, 5, 6, 7, byte(i)}))
+		span.Attributes().PutStr("http.method", "GET")
+		span.Attributes().PutInt("http.status_code", 200)
+	}
+
+	expectedTraces := []json.Marshaler{
+		ptraceotlp.NewExportRequestFromTraces(traces),
+	}
+
+	diff, err := DiffEquiv(expectedTraces, expectedTraces)
+	assert.NoError(t, err)
+	assert.True(t, diff == nil)
+
+	otherTraces := ptrace.NewTraces()
+	traces.CopyTo(otherTraces)
+	otherTraces.ResourceSpans().At(0).ScopeSpans().At(0).Spans().At(0).SetName("span2")
+
+	actualTraces := []json.Marshaler{
+		ptraceotlp.NewExportRequestFromTraces(otherTraces),
+	}
+
+	// Only the name of the first span is reported, the other fields of the
+	// span and the other records are unchanged.
+	diff, err = DiffEquiv(expectedTraces, actualTraces)
+	assert.NoError(t, err)
+	assert.True(t, diff != nil)
+	assert.DeepEqual(t, diff.Changed, []FieldDiff{{
+		Record:       "resourceSpans[0].scopeSpans[0].spans[0]",
+		ActualRecord: "resourceSpans[0].scopeSpans[0].spans[0]",
+		Field:        "name",
+		Expected:     "span1",
+		Actual:       "span2",
+	}})
+	assert.Equal(t, len(diff.Missing), 0)
+	assert.Equal(t, len(diff.Unexpected), 0)
+	assert.Equal(t, diff.String(), "Changed fields:\n~ resourceSpans[0].scopeSpans[0].spans[0].name: expected \"span1\", actual \"span2\"\n")
+
+	// A span without counterpart is reported as missing.
+	otherTraces = ptrace.NewTraces()
+	traces.CopyTo(otherTraces)
+	otherTraces.ResourceSpans().At(0).ScopeSpans().At(0).Spans().RemoveIf(func(span ptrace.Span) bool {
+		return span.Name() == "other"
+	})
+
+	actualTraces = []json.Marshaler{
+		ptraceotlp.NewExportRequestFromTraces(otherTraces),
+	}
+
+	diff, err = DiffEquiv(expectedTraces, actualTraces)
+	assert.NoError(t, err)
+	assert.True(t, diff != nil)
+	assert.Equal(t, len(diff.Changed), 0)
+	assert.Equal(t, len(diff.Unexpected), 0)
+	assert.True(t, hasSuffix(diff.Missing, "spans[1].name=other"))
+	assert.True(t, hasSuffix(diff.Missing, "spans[1].attributes[http.method].value.stringValue=GET"))
+	assert.False(t, hasSuffix(diff.Missing, ".name=span1"))
+}
+
 func TestEquivSortAndMerge(t *testing.T) {
 	t.Parallel()
 
@@ -598,6 +670,15 @@ func TestTryAttributesSig(t *testing.T) {
 	assert.False(t, done)
 }
 
+func hasSuffix(vPaths []string, suffix string) bool {
+	for _, vPath := range vPaths {
+		if strings.HasSuffix(vPath, suffix) {
+			return true
+		}
+	}
+	return false
+}
+
 func attribute(key string, value interface{}) interface{} {
 	return map[string]interface{}{
 		"key":   key,
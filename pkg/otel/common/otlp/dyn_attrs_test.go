@@ -18,6 +18,7 @@
 package otlp
 
 import (
+	"math/rand"
 	"testing"
 
 	"github.com/apache/arrow/go/v12/arrow/memory"
@@ -51,9 +52,12 @@ func TestDynAttrsFromRecord(t *testing.T) {
 	}
 	expected := make(map[uint32]ExpectedAttributes)
 
+	rng := rand.New(rand.NewSource(internal.TestSeed(t))) //nolint:gosec // only used for testing
+	faker := internal.NewTestFaker(rng)
+
 	for i := 0; i < attrCount; i++ {
 		parentID := uint32(i)
-		attrs := internal.RandAttrs(attrNames)
+		attrs := internal.RandAttrs(rng, faker, attrNames)
 		expected[parentID] = ExpectedAttributes{parentID, attrs}
 		err := dynAttrs.Append(parentID, attrs)
 		assert.NoError(t, err)
@@ -21,6 +21,8 @@ import (
 	"math/rand"
 
 	"github.com/apache/arrow/go/v12/arrow"
+	"github.com/apache/arrow/go/v12/arrow/array"
+	"github.com/apache/arrow/go/v12/arrow/memory"
 
 	"github.com/open-telemetry/otel-arrow/pkg/record_message"
 )
@@ -49,3 +51,45 @@ func MixUpArrowRecords(rng *rand.Rand, record arrow.Record, relatedRecords []*re
 
 	return mainRecordChanged, record, relatedRecords
 }
+
+// AppendUnknownColumn returns a copy of the record with an extra trailing
+// column unknown to the decoders, e.g. an optional field added by a newer
+// producer. The returned record must be released by the caller.
+func AppendUnknownColumn(mem memory.Allocator, record arrow.Record) arrow.Record {
+	b := array.NewUint32Builder(mem)
+	defer b.Release()
+	for i := 0; i < int(record.NumRows()); i++ {
+		b.Append(uint32(i))
+	}
+	column := b.NewArray()
+	defer column.Release()
+
+	fields := make([]arrow.Field, 0, record.NumCols()+1)
+	fields = append(fields, record.Schema().Fields()...)
+	fields = append(fields, arrow.Field{Name: "unknown_column", Type: arrow.PrimitiveTypes.Uint32, Nullable: true})
+	metadata := record.Schema().Metadata()
+
+	columns := make([]arrow.Array, 0, record.NumCols()+1)
+	columns = append(columns, record.Columns()...)
+	columns = append(columns, column)
+
+	return array.NewRecord(arrow.NewSchema(fields, &metadata), columns, record.NumRows())
+}
+
+// RemoveColumn returns a copy of the record without the top-level column
+// with the given name, e.g. to simulate a producer omitting a mandatory
+// field. The returned record must be released by the caller.
+func RemoveColumn(record arrow.Record, name string) arrow.Record {
+	fields := make([]arrow.Field, 0, record.NumCols())
+	columns := make([]arrow.Array, 0, record.NumCols())
+	for i, field := range record.Schema().Fields() {
+		if field.Name == name {
+			continue
+		}
+		fields = append(fields, field)
+		columns = append(columns, record.Column(i))
+	}
+	metadata := record.Schema().Metadata()
+
+	return array.NewRecord(arrow.NewSchema(fields, &metadata), columns, record.NumRows())
+}
@@ -0,0 +1,57 @@
+// Copyright The OpenTelemetry Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package internal
+
+import (
+	"math/rand"
+	"os"
+	"strconv"
+	"testing"
+)
+
+const (
+	// SeedEnvVar is the environment variable overriding the seed returned by
+	// TestSeed. It accepts an integer seed, e.g. to reproduce a failing test
+	// run, or RandomSeed to draw a new seed.
+	SeedEnvVar = "OTEL_ARROW_TEST_SEED"
+	// RandomSeed is the SeedEnvVar value selecting a random seed.
+	RandomSeed = "random"
+	// DefaultSeed is the seed returned by TestSeed when SeedEnvVar is not set.
+	DefaultSeed int64 = 12345
+)
+
+// TestSeed returns the seed to use for the test entropy and random number
+// generators of a test. The seed is DefaultSeed unless overridden by the
+// SeedEnvVar environment variable, and is logged so that a failing test can
+// be reproduced by setting SeedEnvVar to the logged value.
+func TestSeed(t testing.TB) int64 {
+	t.Helper()
+
+	seed := DefaultSeed
+	if v, ok := os.LookupEnv(SeedEnvVar); ok {
+		if v == RandomSeed {
+			seed = int64(rand.Uint64()) //nolint:gosec // only used for testing
+		} else {
+			var err error
+			seed, err = strconv.ParseInt(v, 10, 64)
+			if err != nil {
+				t.Fatalf("invalid %s value %q: %v", SeedEnvVar, v, err)
+			}
+		}
+	}
+	t.Logf("test seed: %d (set %s=%d to reproduce)", seed, SeedEnvVar, seed)
+
+	return seed
+}
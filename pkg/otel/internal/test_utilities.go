@@ -15,42 +15,54 @@
 package internal
 
 import (
+	"math/rand"
+
 	"github.com/brianvoe/gofakeit/v6"
 	"go.opentelemetry.io/collector/pdata/pcommon"
 	"go.opentelemetry.io/collector/pdata/pmetric"
-	"golang.org/x/exp/rand"
 )
 
+// NewTestFaker returns a gofakeit.Faker seeded from rng. gofakeit seeds
+// itself from crypto/rand when given a zero seed, hence the remapping.
+func NewTestFaker(rng *rand.Rand) *gofakeit.Faker {
+	seed := rng.Int63()
+	if seed == 0 {
+		seed = 1
+	}
+	return gofakeit.New(seed)
+}
+
 // RandAttrs generates a randon set of attributes with the given names.
 // The number of attributes is random between 1 and the number of names.
-// The type of each attribute is random.
-func RandAttrs(names []string) pcommon.Map {
+// The type of each attribute is random. All random draws come from rng and
+// faker so that the result is reproducible from their seeds.
+func RandAttrs(rng *rand.Rand, faker *gofakeit.Faker, names []string) pcommon.Map {
 	attrs := pcommon.NewMap()
 
-	rand.Shuffle(len(names), func(i, j int) {
+	rng.Shuffle(len(names), func(i, j int) {
 		names[i], names[j] = names[j], names[i]
 	})
 
-	attrCount := rand.Intn(len(names))
+	attrCount := rng.Intn(len(names))
 	if attrCount == 0 {
 		attrCount = 1
 	}
 	names = names[:attrCount]
 
 	for _, name := range names {
-		attrType := rand.Intn(5)
+		attrType := rng.Intn(5)
 		switch attrType {
 		case 0:
-			attrs.PutStr(name, gofakeit.AppName())
+			attrs.PutStr(name, faker.AppName())
 		case 1:
-			attrs.PutInt(name, rand.Int63())
+			attrs.PutInt(name, rng.Int63())
 		case 2:
-			attrs.PutDouble(name, rand.Float64())
+			attrs.PutDouble(name, rng.Float64())
 		case 3:
-			attrs.PutBool(name, rand.Intn(2) == 1)
+			attrs.PutBool(name, rng.Intn(2) == 1)
 		case 4:
 			bytes := attrs.PutEmptyBytes(name)
-			bytes.Append([]byte(gofakeit.UUID())...)
+			bytes.Append([]byte(faker.UUID())...)
 		}
 	}
 	return attrs
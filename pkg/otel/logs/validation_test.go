@@ -23,8 +23,11 @@ import (
 	"github.com/apache/arrow/go/v12/arrow"
 	"github.com/apache/arrow/go/v12/arrow/memory"
 	"github.com/stretchr/testify/require"
+	"go.opentelemetry.io/collector/pdata/pcommon"
+	"go.opentelemetry.io/collector/pdata/plog"
 	"go.opentelemetry.io/collector/pdata/plog/plogotlp"
 
+	colarspb "github.com/open-telemetry/otel-arrow/api/experimental/arrow/v1"
 	"github.com/open-telemetry/otel-arrow/pkg/config"
 	"github.com/open-telemetry/otel-arrow/pkg/datagen"
 	"github.com/open-telemetry/otel-arrow/pkg/otel/assert"
@@ -32,6 +35,8 @@ import (
 	acommon "github.com/open-telemetry/otel-arrow/pkg/otel/common/schema"
 	"github.com/open-telemetry/otel-arrow/pkg/otel/common/schema/builder"
 	cfg "github.com/open-telemetry/otel-arrow/pkg/otel/common/schema/config"
+	"github.com/open-telemetry/otel-arrow/pkg/otel/constants"
+	"github.com/open-telemetry/otel-arrow/pkg/otel/internal"
 	logsarrow "github.com/open-telemetry/otel-arrow/pkg/otel/logs/arrow"
 	logsotlp "github.com/open-telemetry/otel-arrow/pkg/otel/logs/otlp"
 	"github.com/open-telemetry/otel-arrow/pkg/otel/stats"
@@ -53,7 +58,7 @@ var (
 func TestLogsEncodingDecoding(t *testing.T) {
 	t.Parallel()
 
-	entropy := datagen.NewTestEntropy(int64(rand.Uint64())) //nolint:gosec	// only used for testing
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
 	logsGen := datagen.NewLogsGenerator(entropy, entropy.NewStandardResourceAttributes(), entropy.NewStandardInstrumentationScopes())
 
 	expectedRequest := plogotlp.NewExportRequestFromLogs(logsGen.Generate(5000, 100))
@@ -61,6 +66,137 @@ func TestLogsEncodingDecoding(t *testing.T) {
 	CheckEncodeDecode(t, expectedRequest)
 }
 
+// TestLogsAllAttributeTypesEncodingDecoding is similar to
+// TestLogsEncodingDecoding but the generated log attributes cover every
+// AnyValue type, including bytes, maps and slices.
+func TestLogsAllAttributeTypesEncodingDecoding(t *testing.T) {
+	t.Parallel()
+
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
+	logsGen := datagen.NewLogsGenerator(entropy, entropy.NewStandardResourceAttributes(), entropy.NewStandardInstrumentationScopes())
+	conf := datagen.NewDefaultConfig()
+	conf.AllAttributeTypes = true
+	logsGen.WithConfig(conf)
+
+	expectedRequest := plogotlp.NewExportRequestFromLogs(logsGen.Generate(1000, 100))
+
+	// Make sure the rare AnyValue types are actually exercised.
+	valueTypes := make(map[pcommon.ValueType]bool)
+	for _, attrs := range logRecordAttributes(expectedRequest.Logs()) {
+		attrs.Range(func(_ string, v pcommon.Value) bool {
+			valueTypes[v.Type()] = true
+			return true
+		})
+	}
+	for _, vt := range []pcommon.ValueType{pcommon.ValueTypeBytes, pcommon.ValueTypeMap, pcommon.ValueTypeSlice} {
+		require.True(t, valueTypes[vt], "no attribute of type %s generated", vt)
+	}
+
+	CheckEncodeDecode(t, expectedRequest)
+}
+
+// TestWideLogsEncodingDecoding is similar to TestLogsEncodingDecoding but the
+// random log records carry a large number of attributes, stressing the
+// dictionary encoder and the attribute schema.
+func TestWideLogsEncodingDecoding(t *testing.T) {
+	t.Parallel()
+
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
+	logsGen := datagen.NewLogsGenerator(entropy, entropy.NewStandardResourceAttributes(), entropy.NewStandardInstrumentationScopes())
+	conf := datagen.NewDefaultConfig()
+	conf.MinRandomAttrs = 50
+	conf.MaxRandomAttrs = 100
+	logsGen.WithConfig(conf)
+
+	expectedRequest := plogotlp.NewExportRequestFromLogs(logsGen.Generate(100, 100))
+
+	// Random attributes (named attr_<n>) are only set on random log records,
+	// their number must be within the configured range.
+	for _, attrs := range logRecordAttributes(expectedRequest.Logs()) {
+		if _, ok := attrs.Get("attr_0"); ok {
+			require.GreaterOrEqual(t, attrs.Len(), conf.MinRandomAttrs)
+			require.Less(t, attrs.Len(), conf.MaxRandomAttrs)
+		}
+	}
+
+	CheckEncodeDecode(t, expectedRequest)
+}
+
+// TestFixedAttrCountLogsEncodingDecoding checks that a MaxRandomAttrs lower
+// than MinRandomAttrs gives every random log record exactly MinRandomAttrs
+// attributes.
+func TestFixedAttrCountLogsEncodingDecoding(t *testing.T) {
+	t.Parallel()
+
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
+	logsGen := datagen.NewLogsGenerator(entropy, entropy.NewStandardResourceAttributes(), entropy.NewStandardInstrumentationScopes())
+	conf := datagen.NewDefaultConfig()
+	conf.MinRandomAttrs = 20
+	logsGen.WithConfig(conf)
+
+	expectedRequest := plogotlp.NewExportRequestFromLogs(logsGen.Generate(100, 100))
+
+	for _, attrs := range logRecordAttributes(expectedRequest.Logs()) {
+		if _, ok := attrs.Get("attr_0"); ok {
+			require.Equal(t, conf.MinRandomAttrs, attrs.Len())
+		}
+	}
+
+	CheckEncodeDecode(t, expectedRequest)
+}
+
+// TestSkewedAttrCountLogsEncodingDecoding checks that MeanRandomAttrs sets the
+// mean number of random attributes and skews the distribution towards
+// MinRandomAttrs.
+func TestSkewedAttrCountLogsEncodingDecoding(t *testing.T) {
+	t.Parallel()
+
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
+	conf := datagen.NewDefaultConfig()
+	conf.MinRandomAttrs = 2
+	conf.MaxRandomAttrs = 500
+	conf.MeanRandomAttrs = 10
+
+	dg := datagen.NewDataGenerator(entropy, entropy.NewStandardResourceAttributes(), entropy.NewStandardInstrumentationScopes()).
+		WithConfig(conf)
+	const draws = 10000
+	total, belowMean := 0, 0
+	for i := 0; i < draws; i++ {
+		count := dg.RandomAttributes().Len()
+		require.GreaterOrEqual(t, count, conf.MinRandomAttrs)
+		require.Less(t, count, conf.MaxRandomAttrs)
+		total += count
+		if float64(count) < conf.MeanRandomAttrs {
+			belowMean++
+		}
+	}
+	require.InDelta(t, conf.MeanRandomAttrs, float64(total)/draws, 0.5)
+	// A uniform draw with the same mean would put half of the counts below the
+	// mean, the geometric draw puts about 61% of them.
+	require.Greater(t, belowMean, draws*55/100)
+
+	logsGen := datagen.NewLogsGenerator(entropy, entropy.NewStandardResourceAttributes(), entropy.NewStandardInstrumentationScopes())
+	logsGen.WithConfig(conf)
+
+	CheckEncodeDecode(t, plogotlp.NewExportRequestFromLogs(logsGen.Generate(100, 100)))
+}
+
+// logRecordAttributes returns the attributes of every log record.
+func logRecordAttributes(logs plog.Logs) []pcommon.Map {
+	var attrs []pcommon.Map
+	resLogs := logs.ResourceLogs()
+	for i := 0; i < resLogs.Len(); i++ {
+		scopeLogs := resLogs.At(i).ScopeLogs()
+		for j := 0; j < scopeLogs.Len(); j++ {
+			logRecords := scopeLogs.At(j).LogRecords()
+			for k := 0; k < logRecords.Len(); k++ {
+				attrs = append(attrs, logRecords.At(k).Attributes())
+			}
+		}
+	}
+	return attrs
+}
+
 // TestInvalidLogsDecoding is similar to TestLogsEncodingDecoding but introduces
 // some random modification of the Arrow Records used to represent OTel logs.
 // These modifications should be handled gracefully by the decoding process and
@@ -68,7 +204,7 @@ func TestLogsEncodingDecoding(t *testing.T) {
 func TestInvalidLogsDecoding(t *testing.T) {
 	t.Parallel()
 
-	entropy := datagen.NewTestEntropy(int64(rand.Uint64())) //nolint:gosec	// only used for testing
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
 	logsGen := datagen.NewLogsGenerator(entropy, entropy.NewStandardResourceAttributes(), entropy.NewStandardInstrumentationScopes())
 
 	expectedRequest := plogotlp.NewExportRequestFromLogs(logsGen.Generate(100, 100))
@@ -76,12 +212,79 @@ func TestInvalidLogsDecoding(t *testing.T) {
 	MultiRoundOfCheckEncodeMessUpDecode(t, expectedRequest)
 }
 
+// TestLogsDecodingWithUnknownColumn checks that logs records carrying a column
+// unknown to the decoder (e.g. added by a newer producer) are still decoded,
+// the extra column being ignored. Both the main record and the related
+// (attributes) records are extended.
+func TestLogsDecodingWithUnknownColumn(t *testing.T) {
+	t.Parallel()
+
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
+	logsGen := datagen.NewLogsGenerator(entropy, entropy.NewStandardResourceAttributes(), entropy.NewStandardInstrumentationScopes())
+
+	expectedRequest := plogotlp.NewExportRequestFromLogs(logsGen.Generate(100, 100))
+
+	checkEncodeDecode(t, expectedRequest, func(mem memory.Allocator, _ record_message.PayloadType, record arrow.Record) arrow.Record {
+		return common.AppendUnknownColumn(mem, record)
+	})
+}
+
+// TestLogsDecodingWithMissingMandatoryColumn checks that decoding fails when
+// a mandatory column (here the key of the log attributes) is missing.
+func TestLogsDecodingWithMissingMandatoryColumn(t *testing.T) {
+	t.Parallel()
+
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
+	logsGen := datagen.NewLogsGenerator(entropy, entropy.NewStandardResourceAttributes(), entropy.NewStandardInstrumentationScopes())
+
+	expectedRequest := plogotlp.NewExportRequestFromLogs(logsGen.Generate(100, 100))
+
+	removed := false
+	_, err := encodeTransformDecode(t, expectedRequest, func(_ memory.Allocator, payloadType record_message.PayloadType, record arrow.Record) arrow.Record {
+		if payloadType != colarspb.ArrowPayloadType_LOG_ATTRS {
+			return record
+		}
+		removed = true
+		return common.RemoveColumn(record, constants.AttributeKey)
+	})
+	require.True(t, removed, "no log attributes record")
+	require.Error(t, err)
+}
+
 func CheckEncodeDecode(
 	t *testing.T,
 	expectedRequest plogotlp.ExportRequest,
+) {
+	checkEncodeDecode(t, expectedRequest, nil)
+}
+
+// recordTransform returns a transformed version of an Arrow record of the
+// given payload type, or the record itself if left unchanged.
+type recordTransform func(mem memory.Allocator, payloadType record_message.PayloadType, record arrow.Record) arrow.Record
+
+// checkEncodeDecode is similar to CheckEncodeDecode but optionally transforms
+// the Arrow records before decoding them.
+func checkEncodeDecode(
+	t *testing.T,
+	expectedRequest plogotlp.ExportRequest,
+	transform recordTransform,
 ) {
 	stdTesting := assert.NewStdUnitTest(t)
 
+	logs, err := encodeTransformDecode(t, expectedRequest, transform)
+	require.NoError(t, err)
+
+	assert.Equiv(stdTesting, []json.Marshaler{expectedRequest}, []json.Marshaler{plogotlp.NewExportRequestFromLogs(logs)})
+}
+
+// encodeTransformDecode converts the OTLP logs request to Arrow, applies the
+// optional transform to the main record and to each related record, and
+// converts the result back to OTLP.
+func encodeTransformDecode(
+	t *testing.T,
+	expectedRequest plogotlp.ExportRequest,
+	transform recordTransform,
+) (plog.Logs, error) {
 	// Convert the OTLP logs request to Arrow.
 	pool := memory.NewCheckedAllocator(memory.NewGoAllocator())
 	defer pool.AssertSize(t, 0)
@@ -111,22 +314,34 @@ func CheckEncodeDecode(
 		require.Error(t, acommon.ErrSchemaNotUpToDate)
 	}
 
+	if transform != nil {
+		if transformed := transform(pool, colarspb.ArrowPayloadType_LOGS, record); transformed != record {
+			record.Release()
+			record = transformed
+		}
+		for _, relatedRecord := range relatedRecords {
+			if transformed := transform(pool, relatedRecord.PayloadType(), relatedRecord.Record()); transformed != relatedRecord.Record() {
+				relatedRecord.Record().Release()
+				relatedRecord.SetRecord(transformed)
+			}
+		}
+	}
+	defer record.Release()
+
 	relatedData, _, err := logsotlp.RelatedDataFrom(relatedRecords)
-	require.NoError(t, err)
+	if err != nil {
+		return plog.NewLogs(), err
+	}
 
 	// Convert the Arrow records back to OTLP.
-	logs, err := logsotlp.LogsFrom(record, relatedData)
-	record.Release()
-	require.NoError(t, err)
-
-	assert.Equiv(stdTesting, []json.Marshaler{expectedRequest}, []json.Marshaler{plogotlp.NewExportRequestFromLogs(logs)})
+	return logsotlp.LogsFrom(record, relatedData)
 }
 
 func MultiRoundOfCheckEncodeMessUpDecode(
 	t *testing.T,
 	expectedRequest plogotlp.ExportRequest,
 ) {
-	rng := rand.New(rand.NewSource(int64(rand.Uint64())))
+	rng := rand.New(rand.NewSource(internal.TestSeed(t)))
 
 	for i := 0; i < 100; i++ {
 		CheckEncodeMessUpDecode(t, expectedRequest, rng)
@@ -23,6 +23,8 @@ import (
 	"github.com/apache/arrow/go/v12/arrow"
 	"github.com/apache/arrow/go/v12/arrow/memory"
 	"github.com/stretchr/testify/require"
+	"go.opentelemetry.io/collector/pdata/pcommon"
+	"go.opentelemetry.io/collector/pdata/pmetric"
 	"go.opentelemetry.io/collector/pdata/pmetric/pmetricotlp"
 
 	"github.com/open-telemetry/otel-arrow/pkg/config"
@@ -32,6 +34,7 @@ import (
 	"github.com/open-telemetry/otel-arrow/pkg/otel/common/schema"
 	"github.com/open-telemetry/otel-arrow/pkg/otel/common/schema/builder"
 	cfg "github.com/open-telemetry/otel-arrow/pkg/otel/common/schema/config"
+	"github.com/open-telemetry/otel-arrow/pkg/otel/internal"
 	ametrics "github.com/open-telemetry/otel-arrow/pkg/otel/metrics/arrow"
 	"github.com/open-telemetry/otel-arrow/pkg/otel/metrics/otlp"
 	"github.com/open-telemetry/otel-arrow/pkg/otel/stats"
@@ -47,16 +50,94 @@ var DefaultDictConfig = cfg.NewDictionary(math.MaxUint16, 0.0)
 func TestMetricsEncodingDecoding(t *testing.T) {
 	t.Parallel()
 
-	metricsGen := MetricsGenerator()
+	metricsGen := MetricsGenerator(t)
 	expectedRequest := pmetricotlp.NewExportRequestFromMetrics(metricsGen.GenerateRandomMetrics(50, 100))
 
 	CheckEncodeDecode(t, expectedRequest)
 }
 
+// TestMetricsAllAttributeTypesEncodingDecoding is similar to
+// TestMetricsEncodingDecoding but the data point and exemplar attributes cover
+// every AnyValue type, including bytes, maps and slices.
+func TestMetricsAllAttributeTypesEncodingDecoding(t *testing.T) {
+	t.Parallel()
+
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
+	dg := datagen.NewDataGenerator(entropy, entropy.NewStandardResourceAttributes(), entropy.NewStandardInstrumentationScopes())
+	conf := datagen.NewDefaultConfig()
+	conf.AllAttributeTypes = true
+	metricsGen := datagen.NewMetricsGeneratorWithDataGenerator(dg.WithConfig(conf))
+
+	expectedRequest := pmetricotlp.NewExportRequestFromMetrics(metricsGen.GenerateAllKindOfMetrics(100, 100))
+
+	// Make sure the rare AnyValue types are actually exercised, both in data
+	// point and in exemplar attributes.
+	dpValueTypes := make(map[pcommon.ValueType]bool)
+	exemplarValueTypes := make(map[pcommon.ValueType]bool)
+	collect := func(valueTypes map[pcommon.ValueType]bool) func(string, pcommon.Value) bool {
+		return func(_ string, v pcommon.Value) bool {
+			valueTypes[v.Type()] = true
+			return true
+		}
+	}
+	collectExemplars := func(exemplars pmetric.ExemplarSlice) {
+		for i := 0; i < exemplars.Len(); i++ {
+			exemplars.At(i).FilteredAttributes().Range(collect(exemplarValueTypes))
+		}
+	}
+	resMetrics := expectedRequest.Metrics().ResourceMetrics()
+	for i := 0; i < resMetrics.Len(); i++ {
+		scopeMetrics := resMetrics.At(i).ScopeMetrics()
+		for j := 0; j < scopeMetrics.Len(); j++ {
+			metrics := scopeMetrics.At(j).Metrics()
+			for k := 0; k < metrics.Len(); k++ {
+				metric := metrics.At(k)
+				switch metric.Type() {
+				case pmetric.MetricTypeGauge:
+					dps := metric.Gauge().DataPoints()
+					for l := 0; l < dps.Len(); l++ {
+						dps.At(l).Attributes().Range(collect(dpValueTypes))
+						collectExemplars(dps.At(l).Exemplars())
+					}
+				case pmetric.MetricTypeSum:
+					dps := metric.Sum().DataPoints()
+					for l := 0; l < dps.Len(); l++ {
+						dps.At(l).Attributes().Range(collect(dpValueTypes))
+						collectExemplars(dps.At(l).Exemplars())
+					}
+				case pmetric.MetricTypeHistogram:
+					dps := metric.Histogram().DataPoints()
+					for l := 0; l < dps.Len(); l++ {
+						dps.At(l).Attributes().Range(collect(dpValueTypes))
+						collectExemplars(dps.At(l).Exemplars())
+					}
+				case pmetric.MetricTypeExponentialHistogram:
+					dps := metric.ExponentialHistogram().DataPoints()
+					for l := 0; l < dps.Len(); l++ {
+						dps.At(l).Attributes().Range(collect(dpValueTypes))
+						collectExemplars(dps.At(l).Exemplars())
+					}
+				case pmetric.MetricTypeSummary:
+					dps := metric.Summary().DataPoints()
+					for l := 0; l < dps.Len(); l++ {
+						dps.At(l).Attributes().Range(collect(dpValueTypes))
+					}
+				}
+			}
+		}
+	}
+	for _, vt := range []pcommon.ValueType{pcommon.ValueTypeBytes, pcommon.ValueTypeMap, pcommon.ValueTypeSlice} {
+		require.True(t, dpValueTypes[vt], "no data point attribute of type %s generated", vt)
+		require.True(t, exemplarValueTypes[vt], "no exemplar attribute of type %s generated", vt)
+	}
+
+	CheckEncodeDecode(t, expectedRequest)
+}
+
 func TestInvalidMetricsDecoding(t *testing.T) {
 	t.Parallel()
 
-	metricsGen := MetricsGenerator()
+	metricsGen := MetricsGenerator(t)
 	expectedRequest := pmetricotlp.NewExportRequestFromMetrics(metricsGen.GenerateAllKindOfMetrics(100, 100))
 
 	MultiRoundOfCheckEncodeMessUpDecode(t, expectedRequest)
@@ -65,7 +146,7 @@ func TestInvalidMetricsDecoding(t *testing.T) {
 func TestGauges(t *testing.T) {
 	t.Parallel()
 
-	metricsGen := MetricsGenerator()
+	metricsGen := MetricsGenerator(t)
 	expectedRequest := pmetricotlp.NewExportRequestFromMetrics(metricsGen.GenerateGauges(100, 100))
 
 	CheckEncodeDecode(t, expectedRequest)
@@ -75,7 +156,7 @@ func TestGauges(t *testing.T) {
 func TestSums(t *testing.T) {
 	t.Parallel()
 
-	metricsGen := MetricsGenerator()
+	metricsGen := MetricsGenerator(t)
 	expectedRequest := pmetricotlp.NewExportRequestFromMetrics(metricsGen.GenerateSums(100, 100))
 
 	CheckEncodeDecode(t, expectedRequest)
@@ -85,7 +166,7 @@ func TestSums(t *testing.T) {
 func TestSummaries(t *testing.T) {
 	t.Parallel()
 
-	metricsGen := MetricsGenerator()
+	metricsGen := MetricsGenerator(t)
 	expectedRequest := pmetricotlp.NewExportRequestFromMetrics(metricsGen.GenerateSummaries(100, 100))
 
 	CheckEncodeDecode(t, expectedRequest)
@@ -95,7 +176,7 @@ func TestSummaries(t *testing.T) {
 func TestHistograms(t *testing.T) {
 	t.Parallel()
 
-	metricsGen := MetricsGenerator()
+	metricsGen := MetricsGenerator(t)
 	expectedRequest := pmetricotlp.NewExportRequestFromMetrics(metricsGen.GenerateHistograms(100, 100))
 
 	CheckEncodeDecode(t, expectedRequest)
@@ -105,15 +186,15 @@ func TestHistograms(t *testing.T) {
 func TestExponentialHistograms(t *testing.T) {
 	t.Parallel()
 
-	metricsGen := MetricsGenerator()
+	metricsGen := MetricsGenerator(t)
 	expectedRequest := pmetricotlp.NewExportRequestFromMetrics(metricsGen.GenerateExponentialHistograms(100, 100))
 
 	CheckEncodeDecode(t, expectedRequest)
 	MultiRoundOfCheckEncodeMessUpDecode(t, expectedRequest)
 }
 
-func MetricsGenerator() *datagen.MetricsGenerator {
-	entropy := datagen.NewTestEntropy(int64(rand.Uint64())) //nolint:gosec // only used for testing
+func MetricsGenerator(t *testing.T) *datagen.MetricsGenerator {
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
 
 	dg := datagen.NewDataGenerator(entropy, entropy.NewStandardResourceAttributes(), entropy.NewStandardInstrumentationScopes()).
 		WithConfig(datagen.Config{
@@ -175,7 +256,7 @@ func CheckEncodeDecode(t *testing.T, expectedRequest pmetricotlp.ExportRequest)
 // records in order to test the robustness of the conversion. In this situation,
 // the conversion can generate errors, but should never panic.
 func MultiRoundOfCheckEncodeMessUpDecode(t *testing.T, expectedRequest pmetricotlp.ExportRequest) {
-	rng := rand.New(rand.NewSource(int64(rand.Uint64())))
+	rng := rand.New(rand.NewSource(internal.TestSeed(t)))
 
 	for i := 0; i < 100; i++ {
 		OneRoundOfMessUpArrowRecords(t, expectedRequest, rng)
@@ -24,6 +24,7 @@ import (
 	"github.com/apache/arrow/go/v12/arrow"
 	"github.com/apache/arrow/go/v12/arrow/memory"
 	"github.com/stretchr/testify/require"
+	"go.opentelemetry.io/collector/pdata/pcommon"
 	"go.opentelemetry.io/collector/pdata/ptrace"
 	"go.opentelemetry.io/collector/pdata/ptrace/ptraceotlp"
 
@@ -35,6 +36,7 @@ import (
 	acommon "github.com/open-telemetry/otel-arrow/pkg/otel/common/schema"
 	"github.com/open-telemetry/otel-arrow/pkg/otel/common/schema/builder"
 	cfg "github.com/open-telemetry/otel-arrow/pkg/otel/common/schema/config"
+	"github.com/open-telemetry/otel-arrow/pkg/otel/internal"
 	"github.com/open-telemetry/otel-arrow/pkg/otel/stats"
 	tracesarrow "github.com/open-telemetry/otel-arrow/pkg/otel/traces/arrow"
 	tracesotlp "github.com/open-telemetry/otel-arrow/pkg/otel/traces/otlp"
@@ -54,7 +56,7 @@ var ProducerStats = stats.NewProducerStats()
 func TestTracesEncodingDecoding(t *testing.T) {
 	t.Parallel()
 
-	entropy := datagen.NewTestEntropy(int64(rand.Uint64())) //nolint:gosec // only used for testing
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
 
 	tracesGen := datagen.NewTracesGenerator(
 		entropy,
@@ -70,7 +72,7 @@ func TestTracesEncodingDecoding(t *testing.T) {
 func TestRandomTracesEncodingDecoding(t *testing.T) {
 	t.Parallel()
 
-	entropy := datagen.NewTestEntropy(int64(rand.Uint64())) //nolint:gosec // only used for testing
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
 
 	tracesGen := datagen.NewTracesGenerator(
 		entropy,
@@ -78,6 +80,52 @@ func TestRandomTracesEncodingDecoding(t *testing.T) {
 		entropy.NewRandomInstrumentationScopes(10),
 	)
 
+	// Make sure the rare AnyValue types are actually exercised.
+	valueTypes := make(map[pcommon.ValueType]bool)
+	collect := func(_ string, v pcommon.Value) bool {
+		valueTypes[v.Type()] = true
+		return true
+	}
+
+	for i := 0; i < 100; i++ {
+		expectedRequest := ptraceotlp.NewExportRequestFromTraces(
+			tracesGen.GenerateRandomTraces(1, 100))
+
+		resSpans := expectedRequest.Traces().ResourceSpans()
+		for j := 0; j < resSpans.Len(); j++ {
+			scopeSpans := resSpans.At(j).ScopeSpans()
+			for k := 0; k < scopeSpans.Len(); k++ {
+				spans := scopeSpans.At(k).Spans()
+				for l := 0; l < spans.Len(); l++ {
+					spans.At(l).Attributes().Range(collect)
+				}
+			}
+		}
+
+		CheckEncodeDecode(t, expectedRequest)
+	}
+
+	for _, vt := range []pcommon.ValueType{pcommon.ValueTypeBytes, pcommon.ValueTypeMap, pcommon.ValueTypeSlice} {
+		require.True(t, valueTypes[vt], "no attribute of type %s generated", vt)
+	}
+}
+
+// TestAllAttributeTypesTracesEncodingDecoding checks the round trip of random
+// traces whose span attributes cover every AnyValue type.
+func TestAllAttributeTypesTracesEncodingDecoding(t *testing.T) {
+	t.Parallel()
+
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
+
+	tracesGen := datagen.NewTracesGenerator(
+		entropy,
+		entropy.NewRandomResourceAttributes(10),
+		entropy.NewRandomInstrumentationScopes(10),
+	)
+	conf := datagen.NewDefaultConfig()
+	conf.AllAttributeTypes = true
+	tracesGen.WithConfig(conf)
+
 	for i := 0; i < 100; i++ {
 		expectedRequest := ptraceotlp.NewExportRequestFromTraces(
 			tracesGen.GenerateRandomTraces(1, 100))
@@ -270,7 +318,7 @@ func TestCustom2TracesEncodingDecoding(t *testing.T) {
 func TestInvalidTracesDecoding(t *testing.T) {
 	t.Parallel()
 
-	entropy := datagen.NewTestEntropy(int64(rand.Uint64())) //nolint:gosec // only used for testing
+	entropy := datagen.NewTestEntropy(internal.TestSeed(t))
 
 	tracesGen := datagen.NewTracesGenerator(entropy, entropy.NewStandardResourceAttributes(), entropy.NewStandardInstrumentationScopes())
 
@@ -330,7 +378,7 @@ func MultiRoundOfCheckEncodeMessUpDecode(
 	t *testing.T,
 	expectedRequest ptraceotlp.ExportRequest,
 ) {
-	rng := rand.New(rand.NewSource(int64(rand.Uint64())))
+	rng := rand.New(rand.NewSource(internal.TestSeed(t)))
 
 	for i := 0; i < 100; i++ {
 		CheckEncodeMessUpDecode(t, expectedRequest, rng)